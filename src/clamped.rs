@@ -0,0 +1,80 @@
+use crate::{ToIndex, TryFromIndex};
+
+/// Key wrapper around an unsigned integer that documents at the type level
+/// that stepping past the integer's maximum yields `None`.
+///
+/// A `Clamped<T>` key behaves exactly like its inner integer key.
+/// [`Key::add_one()`](crate::Key::add_one) and [`Key::add_usize()`](crate::Key::add_usize)
+/// never wrap or panic, instead returning `None` at the upper bound.
+/// ```
+/// use contiguous_map::{Clamped, Key};
+///
+/// assert_eq!(Some(Clamped(255u8)), Clamped(254u8).add_one());
+/// assert_eq!(None, Clamped(255u8).add_one());
+/// ```
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+pub struct Clamped<T>(pub T);
+
+macro_rules! clamped_key_impl {
+    ($($type:ty),*) => {
+        $(
+            impl ToIndex for Clamped<$type> {
+                type Index = $type;
+
+                fn to_index(&self) -> Self::Index {
+                    self.0
+                }
+            }
+
+            impl TryFromIndex for Clamped<$type> {
+                fn try_from_index(index: Self::Index) -> Option<Self> {
+                    Some(Clamped(index))
+                }
+            }
+        )*
+    };
+}
+
+clamped_key_impl!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_util::assert_regions, ContiguousMap, Key};
+
+    #[test]
+    fn add_one_at_bound() {
+        assert_eq!(Some(Clamped(1u8)), Clamped(0u8).add_one());
+        assert_eq!(None, Clamped(u8::MAX).add_one());
+        assert_eq!(None, Clamped(u128::MAX).add_one());
+    }
+
+    #[test]
+    fn add_usize_at_bound() {
+        assert_eq!(Some(Clamped(u16::MAX)), Clamped(u16::MAX - 3).add_usize(3));
+        assert_eq!(None, Clamped(u16::MAX - 3).add_usize(4));
+        assert_eq!(None, Clamped(0u8).add_usize(256));
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(Some(3), Clamped(5u32).difference(&Clamped(2u32)));
+        assert_eq!(None, Clamped(2u32).difference(&Clamped(5u32)));
+    }
+
+    #[test]
+    fn matches_inner_key() {
+        let keys = [7u8, 3, 255, 4, 254, 10];
+        let mut map = ContiguousMap::new();
+        let mut clamped_map = ContiguousMap::new();
+        for key in keys {
+            map.insert(key, key);
+            clamped_map.insert(Clamped(key), key);
+        }
+        let regions: Vec<_> = map
+            .iter_slice()
+            .map(|(key, values)| (Clamped(*key), values.to_vec()))
+            .collect();
+        assert_regions(&clamped_map, &regions);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{ToIndex, TryFromIndex};
+use core::cmp::Ordering;
+
+/// Key adapter that reverses the order of the wrapped key,
+/// similar to [`core::cmp::Reverse`].
+///
+/// A [`ContiguousMap`](crate::ContiguousMap) keyed by `Descending<K>`
+/// iterates from the largest inner key to the smallest.
+/// Keys that are adjacent in the inner key type are still adjacent
+/// when wrapped, so contiguous regions are preserved.
+///
+/// [`Key`](crate::Key) is implemented when wrapping the primitive integer types and [`char`].
+/// ```
+/// use contiguous_map::{ContiguousMap, Descending};
+///
+/// let mut map = ContiguousMap::new();
+/// map.insert(Descending(1u8), 'a');
+/// map.insert(Descending(2u8), 'b');
+/// assert_eq!(1, map.num_contiguous_regions());
+/// assert_eq!(Some(&['b', 'a'][..]), map.get_slice(Descending(2u8)..));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Descending<K>(pub K);
+
+impl<K: Ord> PartialOrd for Descending<K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord> Ord for Descending<K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+macro_rules! descending_unsigned_key_impl {
+    ($($type:ty),*) => {
+        $(
+            impl ToIndex for Descending<$type> {
+                type Index = $type;
+
+                fn to_index(&self) -> Self::Index {
+                    <$type>::MAX - self.0
+                }
+            }
+
+            impl TryFromIndex for Descending<$type> {
+                fn try_from_index(index: Self::Index) -> Option<Self> {
+                    Some(Descending(<$type>::MAX - index))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! descending_indexed_key_impl {
+    ($($type:ty),*) => {
+        $(
+            impl ToIndex for Descending<$type> {
+                type Index = <$type as ToIndex>::Index;
+
+                fn to_index(&self) -> Self::Index {
+                    Self::Index::MAX - self.0.to_index()
+                }
+            }
+
+            impl TryFromIndex for Descending<$type> {
+                fn try_from_index(index: Self::Index) -> Option<Self> {
+                    <$type>::try_from_index(Self::Index::MAX - index).map(Descending)
+                }
+            }
+        )*
+    };
+}
+
+descending_unsigned_key_impl!(u8, u16, u32, u64, u128, usize);
+descending_indexed_key_impl!(i8, i16, i32, i64, i128, isize, char);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_util::assert_regions, ContiguousMap, Key};
+
+    #[test]
+    fn ordering() {
+        assert!(Descending(2u8) < Descending(1u8));
+        assert!(Descending(-1i8) > Descending(0i8));
+        assert_eq!(Descending(3u8), Descending(3u8));
+    }
+
+    #[test]
+    fn add_one() {
+        assert_eq!(Some(Descending(4u8)), Descending(5u8).add_one());
+        assert_eq!(None, Descending(0u8).add_one());
+        assert_eq!(Some(Descending(i8::MIN)), Descending(i8::MIN + 1).add_one());
+        assert_eq!(None, Descending(i8::MIN).add_one());
+        assert_eq!(Some(Descending('a')), Descending('b').add_one());
+        assert_eq!(
+            Some(Descending('\u{D7FF}')),
+            Descending('\u{E000}').add_one()
+        );
+        assert_eq!(None, Descending('\0').add_one());
+    }
+
+    #[test]
+    fn difference() {
+        assert_eq!(Some(3), Descending(2u8).difference(&Descending(5u8)));
+        assert_eq!(None, Descending(5u8).difference(&Descending(2u8)));
+        assert_eq!(Some(2), Descending(-1i32).difference(&Descending(1i32)));
+    }
+
+    #[test]
+    fn add_usize() {
+        assert_eq!(Some(Descending(2u8)), Descending(5u8).add_usize(3));
+        assert_eq!(Some(Descending(0u8)), Descending(5u8).add_usize(5));
+        assert_eq!(None, Descending(5u8).add_usize(6));
+    }
+
+    #[test]
+    fn iter_descending() {
+        let mut map = ContiguousMap::new();
+        for key in [3u8, 7, 5, 4, 8] {
+            map.insert(Descending(key), key as i32 + 10);
+        }
+        let keys: Vec<_> = map.iter().map(|(Descending(key), _)| key).collect();
+        assert_eq!(vec![8, 7, 5, 4, 3], keys);
+    }
+
+    #[test]
+    fn regions_merge() {
+        let mut map = ContiguousMap::new();
+        map.insert(Descending(5u8), 15);
+        map.insert(Descending(3u8), 13);
+        assert_eq!(2, map.num_contiguous_regions());
+        map.insert(Descending(4u8), 14);
+        assert_regions(&map, &[(Descending(5u8), vec![15, 14, 13])]);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::Key;
+use alloc::boxed::Box;
+use core::{any::Any, cmp::Ordering, fmt};
+
+/// Object safe version of the [`Key`] trait.
+///
+/// This is implemented for every `'static` type that implements [`Key`],
+/// allowing keys whose type is only known at runtime to be stored in a
+/// [`ContiguousMap`](crate::ContiguousMap) through [`BoxedKey`].
+///
+/// Keys of different concrete types are never adjacent.
+/// They are ordered by their [`TypeId`](core::any::TypeId)
+/// so that a map may hold a mix of them.
+pub trait DynKey: Any {
+    /// Object safe version of [`Key::add_one()`].
+    fn dyn_add_one(&self) -> Option<BoxedKey>;
+
+    /// Object safe version of [`Key::sub_one()`].
+    fn dyn_sub_one(&self) -> Option<BoxedKey>;
+
+    /// Object safe version of [`Key::difference()`].
+    /// Returns None if `smaller` is a different concrete type.
+    fn dyn_difference(&self, smaller: &dyn DynKey) -> Option<usize>;
+
+    /// Object safe version of [`Key::add_usize()`].
+    fn dyn_add_usize(&self, num: usize) -> Option<BoxedKey>;
+
+    /// Object safe version of [`Key::sub_usize()`].
+    fn dyn_sub_usize(&self, num: usize) -> Option<BoxedKey>;
+
+    /// Object safe version of [`Ord::cmp()`].
+    fn dyn_cmp(&self, other: &dyn DynKey) -> Ordering;
+
+    /// Object safe version of [`Clone::clone()`].
+    fn dyn_clone(&self) -> BoxedKey;
+
+    /// Gets this key as [`Any`] for downcasting.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<K: Key + Any> DynKey for K {
+    fn dyn_add_one(&self) -> Option<BoxedKey> {
+        self.add_one().map(BoxedKey::new)
+    }
+
+    fn dyn_sub_one(&self) -> Option<BoxedKey> {
+        self.sub_one().map(BoxedKey::new)
+    }
+
+    fn dyn_difference(&self, smaller: &dyn DynKey) -> Option<usize> {
+        self.difference(smaller.as_any().downcast_ref::<K>()?)
+    }
+
+    fn dyn_add_usize(&self, num: usize) -> Option<BoxedKey> {
+        self.add_usize(num).map(BoxedKey::new)
+    }
+
+    fn dyn_sub_usize(&self, num: usize) -> Option<BoxedKey> {
+        self.sub_usize(num).map(BoxedKey::new)
+    }
+
+    fn dyn_cmp(&self, other: &dyn DynKey) -> Ordering {
+        match other.as_any().downcast_ref::<K>() {
+            Some(other) => self.cmp(other),
+            None => self.as_any().type_id().cmp(&other.as_any().type_id()),
+        }
+    }
+
+    fn dyn_clone(&self) -> BoxedKey {
+        BoxedKey::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A boxed [`DynKey`] that implements the [`Key`] trait.
+///
+/// This trades performance for the ability to choose key behavior at runtime.
+/// ```
+/// use contiguous_map::{BoxedKey, ContiguousMap};
+///
+/// let mut map = ContiguousMap::new();
+/// map.insert(BoxedKey::new(1u32), 'a');
+/// map.insert(BoxedKey::new(2u32), 'b');
+/// assert_eq!(1, map.num_contiguous_regions());
+/// assert_eq!(Some(&'b'), map.get(BoxedKey::new(2u32)));
+/// ```
+pub struct BoxedKey(Box<dyn DynKey>);
+
+impl BoxedKey {
+    /// Boxes a key.
+    pub fn new<K: Key + Any>(key: K) -> Self {
+        Self(Box::new(key))
+    }
+
+    /// Gets the boxed key if it is of type `K`.
+    pub fn downcast_ref<K: Key + Any>(&self) -> Option<&K> {
+        self.0.as_any().downcast_ref()
+    }
+}
+
+impl Key for BoxedKey {
+    fn add_one(&self) -> Option<Self> {
+        self.0.dyn_add_one()
+    }
+
+    fn sub_one(&self) -> Option<Self> {
+        self.0.dyn_sub_one()
+    }
+
+    fn difference(&self, smaller: &Self) -> Option<usize> {
+        self.0.dyn_difference(&*smaller.0)
+    }
+
+    fn add_usize(&self, num: usize) -> Option<Self> {
+        self.0.dyn_add_usize(num)
+    }
+
+    fn sub_usize(&self, num: usize) -> Option<Self> {
+        self.0.dyn_sub_usize(num)
+    }
+}
+
+impl Clone for BoxedKey {
+    fn clone(&self) -> Self {
+        self.0.dyn_clone()
+    }
+}
+
+impl PartialEq for BoxedKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BoxedKey {}
+
+impl PartialOrd for BoxedKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BoxedKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.dyn_cmp(&*other.0)
+    }
+}
+
+impl fmt::Debug for BoxedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BoxedKey").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ContiguousMap;
+
+    #[test]
+    fn key_ops() {
+        let key = BoxedKey::new(5u8);
+        assert_eq!(Some(&6u8), key.add_one().unwrap().downcast_ref());
+        assert_eq!(Some(&8u8), key.add_usize(3).unwrap().downcast_ref());
+        assert_eq!(None, BoxedKey::new(u8::MAX).add_one());
+        assert_eq!(Some(&4u8), key.sub_one().unwrap().downcast_ref());
+        assert_eq!(None, BoxedKey::new(0u8).sub_one());
+        assert_eq!(Some(&2u8), key.sub_usize(3).unwrap().downcast_ref());
+        assert_eq!(None, key.sub_usize(6));
+        assert_eq!(Some(3), key.difference(&BoxedKey::new(2u8)));
+        assert_eq!(None, key.difference(&BoxedKey::new(2u16)));
+        assert_eq!(None, key.downcast_ref::<u16>());
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(BoxedKey::new(1i32) < BoxedKey::new(2i32));
+        assert_eq!(BoxedKey::new(1i32), BoxedKey::new(1i32).clone());
+        // different types are never equal
+        assert_ne!(BoxedKey::new(1i32), BoxedKey::new(1u32));
+    }
+
+    #[test]
+    fn matches_static_map() {
+        let keys = [7u32, 3, 4, 10, 5, 11];
+        let mut static_map = ContiguousMap::new();
+        let mut dyn_map = ContiguousMap::new();
+        for key in keys {
+            static_map.insert(key, key + 100);
+            dyn_map.insert(BoxedKey::new(key), key + 100);
+        }
+        assert_eq!(
+            static_map.num_contiguous_regions(),
+            dyn_map.num_contiguous_regions()
+        );
+        for key in 0..15 {
+            assert_eq!(static_map.get(key), dyn_map.get(BoxedKey::new(key)));
+        }
+        let dyn_entries: Vec<_> = dyn_map
+            .iter()
+            .map(|(key, value)| (*key.downcast_ref::<u32>().unwrap(), value))
+            .collect();
+        let static_entries: Vec<_> = static_map.iter().collect();
+        assert_eq!(static_entries, dyn_entries);
+    }
+
+    #[test]
+    fn mixed_key_types() {
+        let mut map = ContiguousMap::new();
+        map.insert(BoxedKey::new(1u8), 'a');
+        map.insert(BoxedKey::new(2u16), 'b');
+        map.insert(BoxedKey::new(2u8), 'c');
+        // keys of different types are never merged
+        assert_eq!(2, map.num_contiguous_regions());
+        assert_eq!(
+            Some(&['a', 'c'][..]),
+            map.get_slice_with_len(BoxedKey::new(1u8), 2)
+        );
+        assert_eq!(Some(&'b'), map.get(BoxedKey::new(2u16)));
+    }
+}
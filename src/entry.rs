@@ -0,0 +1,145 @@
+use super::{ContiguousMap, Index, Key};
+
+/// A view into a single entry in a [`ContiguousMap`],
+/// which may either be vacant or occupied.
+///
+/// See [`ContiguousMap::entry()`].
+pub enum Entry<'a, K: Key, V> {
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, K, V>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+/// A view into a vacant entry in a [`ContiguousMap`].
+/// It is part of the [`Entry`] enum.
+pub struct VacantEntry<'a, K: Key, V> {
+    map: &'a mut ContiguousMap<K, V>,
+    key: K,
+}
+
+/// A view into an occupied entry in a [`ContiguousMap`].
+/// It is part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, K: Key, V> {
+    map: &'a mut ContiguousMap<K, V>,
+    key: K,
+    index: Index<K>,
+}
+
+impl<'a, K: Key, V> Entry<'a, K, V> {
+    pub(crate) fn new(map: &'a mut ContiguousMap<K, V>, key: K) -> Self {
+        match map.find(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map, key, index }),
+            None => Entry::Vacant(VacantEntry { map, key }),
+        }
+    }
+
+    /// Gets the key of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => &entry.key,
+            Entry::Occupied(entry) => &entry.key,
+        }
+    }
+
+    /// Ensures a value is in this entry by inserting the default if empty.
+    /// Returns a mutable reference to the value in this entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Ensures a value is in this entry by inserting the result of the default function if empty.
+    /// Returns a mutable reference to the value in this entry.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Ensures a value is in this entry by inserting the default value if empty.
+    /// Returns a mutable reference to the value in this entry.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        match self {
+            Entry::Vacant(entry) => entry.insert(V::default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry
+    /// before any potential inserts into the map.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Key, V> VacantEntry<'a, K, V> {
+    /// Gets the key that would be used when inserting a value through this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Sets the value of this entry, returning a mutable reference to it.
+    ///
+    /// This merges the value with any adjacent contiguous regions.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.insert(self.key.clone(), value);
+        self.map
+            .get_mut(&self.key)
+            .expect("value was just inserted with this key")
+    }
+}
+
+impl<'a, K: Key, V> OccupiedEntry<'a, K, V> {
+    /// Gets the key of this entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Gets a reference to the value in this entry.
+    pub fn get(&self) -> &V {
+        &self
+            .map
+            .map
+            .get(&self.index.key)
+            .expect("index was found in this map")[self.index.offset]
+    }
+
+    /// Gets a mutable reference to the value in this entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self
+            .map
+            .map
+            .get_mut(&self.index.key)
+            .expect("index was found in this map")[self.index.offset]
+    }
+
+    /// Converts this entry into a mutable reference to its value
+    /// with a lifetime bound to the map itself.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self
+            .map
+            .map
+            .get_mut(&self.index.key)
+            .expect("index was found in this map")[self.index.offset]
+    }
+
+    /// Takes the value of this entry out of the map.
+    ///
+    /// This splits the contiguous region containing this entry
+    /// just like [`ContiguousMap::remove()`].
+    pub fn remove(self) -> V {
+        self.map
+            .remove(&self.key)
+            .expect("entry is occupied so its key is in the map")
+    }
+}
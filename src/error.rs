@@ -0,0 +1,64 @@
+use core::fmt;
+
+/// Error returned by [`ContiguousMap::insert_next()`](crate::ContiguousMap::insert_next)
+/// when the map already contains a value for the maximum key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterFullError;
+
+impl fmt::Display for CounterFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the map already contains a value for the maximum key")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CounterFullError {}
+
+/// Error returned by [`ContiguousMap::shift_keys()`](crate::ContiguousMap::shift_keys)
+/// when shifting a key would overflow the key type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShiftError;
+
+impl fmt::Display for ShiftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("shifting the keys of the map would overflow the key type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShiftError {}
+
+/// Error returned by [`ContiguousMap::try_insert()`](crate::ContiguousMap::try_insert)
+/// when the map already contains a value for the key.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OccupiedError<'a, V> {
+    /// The value already in the map.
+    pub existing: &'a mut V,
+    /// The value that was not inserted.
+    pub value: V,
+}
+
+impl<V> fmt::Display for OccupiedError<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the map already contains a value for the key")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: fmt::Debug> std::error::Error for OccupiedError<'_, V> {}
+
+/// Error returned by [`ContiguousMap::push_back()`](crate::ContiguousMap::push_back)
+/// when the key is not greater than the largest key in the map.
+///
+/// Contains the value that was not inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PushError<V>(pub V);
+
+impl<V> fmt::Display for PushError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the key is not greater than the largest key in the map")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: fmt::Debug> std::error::Error for PushError<V> {}
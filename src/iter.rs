@@ -1,5 +1,6 @@
-use super::{ContiguousMap, Index, Key};
-use std::{collections::btree_map, iter::FusedIterator};
+use super::{ContiguousMap, Index, Key, RegionFilter};
+use alloc::{collections::btree_map, vec::Vec};
+use core::iter::FusedIterator;
 
 /// Implementation function for [`IntoIter`], [`Iter`], and [`IterMut`]'s next() function.
 ///
@@ -7,14 +8,14 @@ use std::{collections::btree_map, iter::FusedIterator};
 ///
 /// # Arguments
 /// * `front_entry` — Produces (Key, Value) pairs that have been extracted from the front of `map_iter` but
-///  have not yet been yielded from the iterator using this function.
+///   have not yet been yielded from the iterator using this function.
 /// * `map_iter` — An iterator that yields something convertible to what is stored in `front_entry`.
-///  This is iterated over to populate `front_entry` whenever it is empty.
+///   This is iterated over to populate `front_entry` whenever it is empty.
 /// * `back_entry` — Produces (Key, Value) pairs that have been extracted from the back of `map_iter` but
-///  have not yet been yielded from the iterator using this function.
-///  Once `map_iter` is exhausted this is consumed to iterate over any potentially remaining values.
+///   have not yet been yielded from the iterator using this function.
+///   Once `map_iter` is exhausted this is consumed to iterate over any potentially remaining values.
 /// * `extract` — Function that is used to convert the values yielded by `map_iter` into what is stored
-///  in `front_entry` and `back_entry`.
+///   in `front_entry` and `back_entry`.
 fn next_impl<K, V, ValIter, MapIter, ExtractFn, ExtractInput>(
     front_entry: &mut Option<(K, ValIter)>,
     mut map_iter: Option<&mut MapIter>,
@@ -31,21 +32,23 @@ where
         // attempt to consume a (K, V) from front_entry
         if let Some((key, iter)) = front_entry {
             if let Some(value) = iter.next() {
-                let item = (key.clone(), value);
+                // the yielded key is moved out instead of cloned,
+                // so keys are only cloned when a region is extracted from map_iter
                 if iter.len() != 0 {
-                    *key = key.add_one().unwrap();
-                } else {
-                    *front_entry = None
+                    let next_key = key
+                        .add_one()
+                        .expect("all values in the map have a valid key");
+                    return Some((core::mem::replace(key, next_key), value));
                 }
-                return Some(item);
+                let (key, _) = front_entry.take().unwrap();
+                return Some((key, value));
             }
         }
 
         // attempt to refill front_entry
         *front_entry = map_iter
             .as_mut()
-            .map(|iter| iter.next())
-            .flatten()
+            .and_then(|iter| iter.next())
             .map(&extract)
             .or_else(|| back_entry.take());
 
@@ -60,14 +63,14 @@ where
 ///
 /// # Arguments
 /// * `front_entry` — Produces (Key, Value) pairs that have been extracted from the front of `map_iter` but
-///  have not yet been yielded from the iterator using this function.
-///  Once `map_iter` is exhausted this is consumed to iterate over any potentially remaining values.
+///   have not yet been yielded from the iterator using this function.
+///   Once `map_iter` is exhausted this is consumed to iterate over any potentially remaining values.
 /// * `map_iter` — An iterator that yields something convertible to what is stored in `back_entry`.
-///  This is iterated over to populate `back_entry` whenever it is empty.
+///   This is iterated over to populate `back_entry` whenever it is empty.
 /// * `back_entry` — Produces (Key, Value) pairs that have been extracted from the back of `map_iter` but
-///  have not yet been yielded from the iterator using this function.
+///   have not yet been yielded from the iterator using this function.
 /// * `extract` — Function that is used to convert the values yielded by `map_iter` into what is stored
-///  in `front_entry` and `back_entry`.
+///   in `front_entry` and `back_entry`.
 fn next_back_impl<K, V, ValIter, MapIter, ExtractFn, ExtractInput>(
     front_entry: &mut Option<(K, ValIter)>,
     mut map_iter: Option<&mut MapIter>,
@@ -84,7 +87,9 @@ where
         // attempt to consume a (K, V) from back_entry
         if let Some((key, iter)) = back_entry {
             if let Some(value) = iter.next_back() {
-                let key = key.add_usize(iter.len()).unwrap();
+                let key = key
+                    .add_usize(iter.len())
+                    .expect("all values in the map have a valid key");
                 return Some((key, value));
             } else {
                 *back_entry = None;
@@ -94,8 +99,7 @@ where
         // attempt to refill back_entry
         *back_entry = map_iter
             .as_mut()
-            .map(|iter| iter.next_back())
-            .flatten()
+            .and_then(|iter| iter.next_back())
             .map(&extract)
             .or_else(|| front_entry.take());
 
@@ -107,9 +111,10 @@ where
 /// An owning iterator over all `(Key, Value)` entries
 /// in a [`ContiguousMap`] in ascending key order.
 pub struct IntoIter<K: Key, V> {
-    front_entry: Option<(K, std::vec::IntoIter<V>)>,
+    front_entry: Option<(K, alloc::vec::IntoIter<V>)>,
     map_iter: btree_map::IntoIter<K, Vec<V>>,
-    back_entry: Option<(K, std::vec::IntoIter<V>)>,
+    back_entry: Option<(K, alloc::vec::IntoIter<V>)>,
+    remaining: usize,
 }
 
 impl<K: Key, V> IntoIterator for ContiguousMap<K, V> {
@@ -121,6 +126,7 @@ impl<K: Key, V> IntoIterator for ContiguousMap<K, V> {
             front_entry: None,
             map_iter: self.map.into_iter(),
             back_entry: None,
+            remaining: self.length,
         }
     }
 }
@@ -129,36 +135,180 @@ impl<K: Key, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        next_impl(
+        let item = next_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k, v.into_iter()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
 impl<K: Key, V> DoubleEndedIterator for IntoIter<K, V> {
     fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
-        next_back_impl(
+        let item = next_back_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k, v.into_iter()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
     }
 }
 
 impl<K: Key, V> FusedIterator for IntoIter<K, V> {}
 
+impl<K: Key, V> ExactSizeIterator for IntoIter<K, V> {}
+
+/// An owning iterator over the `(Key, Value)` entries removed
+/// from a range of keys in a [`ContiguousMap`] in ascending key order.
+///
+/// The entries are removed from the map when this iterator is created,
+/// so dropping it before it is exhausted still leaves the entire range removed.
+/// The removed values are moved out of their detached regions as this iterator is advanced.
+///
+/// See [`ContiguousMap::drain_range()`].
+pub struct DrainRange<K: Key, V> {
+    front_entry: Option<(K, alloc::vec::IntoIter<V>)>,
+    map_iter: alloc::vec::IntoIter<(K, Vec<V>)>,
+    back_entry: Option<(K, alloc::vec::IntoIter<V>)>,
+    remaining: usize,
+}
+
+impl<K: Key, V> DrainRange<K, V> {
+    pub(crate) fn new(regions: Vec<(K, Vec<V>)>) -> Self {
+        let remaining = regions.iter().map(|(_, vec)| vec.len()).sum();
+        Self {
+            front_entry: None,
+            map_iter: regions.into_iter(),
+            back_entry: None,
+            remaining,
+        }
+    }
+}
+
+impl<K: Key, V> Iterator for DrainRange<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = next_impl(
+            &mut self.front_entry,
+            Some(&mut self.map_iter),
+            &mut self.back_entry,
+            |(k, v)| (k, v.into_iter()),
+        )?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K: Key, V> DoubleEndedIterator for DrainRange<K, V> {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        let item = next_back_impl(
+            &mut self.front_entry,
+            Some(&mut self.map_iter),
+            &mut self.back_entry,
+            |(k, v)| (k, v.into_iter()),
+        )?;
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+impl<K: Key, V> FusedIterator for DrainRange<K, V> {}
+
+impl<K: Key, V> ExactSizeIterator for DrainRange<K, V> {}
+
+/// An iterator that removes and yields the `(Key, Value)` entries
+/// of a [`ContiguousMap`] that match a predicate in ascending key order.
+///
+/// Entries are examined one contiguous region at a time as this iterator is advanced.
+/// Dropping it early leaves the entries of every region it has not reached in the map,
+/// while matching entries of the region it was yielding from are still removed.
+///
+/// See [`ContiguousMap::extract_if()`].
+pub struct ExtractIf<'a, K: Key, V, F: FnMut(K, &mut V) -> bool> {
+    map: &'a mut ContiguousMap<K, V>,
+    next_region_key: Option<K>,
+    extracted: alloc::vec::IntoIter<(K, V)>,
+    pred: F,
+}
+
+impl<'a, K: Key, V, F: FnMut(K, &mut V) -> bool> ExtractIf<'a, K, V, F> {
+    pub(crate) fn new(map: &'a mut ContiguousMap<K, V>, pred: F) -> Self {
+        let next_region_key = map.map.keys().next().cloned();
+        Self {
+            map,
+            next_region_key,
+            extracted: Vec::new().into_iter(),
+            pred,
+        }
+    }
+
+    /// Partitions the next unprocessed region of the map in a single pass,
+    /// putting the kept runs back into the map and buffering the extracted entries.
+    /// Returns None once every region has been processed.
+    fn extract_next_region(&mut self) -> Option<()> {
+        let (start_key, len) = self
+            .map
+            .map
+            .range(self.next_region_key.as_ref()?..)
+            .next()
+            .map(|(start_key, vec)| (start_key.clone(), vec.len()))?;
+        // the kept runs are reinserted behind the cursor
+        self.next_region_key = start_key.add_usize(len);
+
+        let mut extracted = Vec::new();
+        let pred = &mut self.pred;
+        RegionFilter::new(self.map, start_key).filter(
+            |key, value| !pred(key, value),
+            |key, value| extracted.push((key, value)),
+        );
+        self.extracted = extracted.into_iter();
+        Some(())
+    }
+}
+
+impl<'a, K: Key, V, F: FnMut(K, &mut V) -> bool> Iterator for ExtractIf<'a, K, V, F> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.extracted.next() {
+                return Some(item);
+            }
+            self.extract_next_region()?;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.extracted.len();
+        (buffered, Some(buffered + self.map.len()))
+    }
+}
+
+impl<'a, K: Key, V, F: FnMut(K, &mut V) -> bool> FusedIterator for ExtractIf<'a, K, V, F> {}
+
 /// An iterator over all `(Key, &Value)` entries
 /// in a [`ContiguousMap`] in ascending key order.
 ///
 /// See [`ContiguousMap::iter()`].
 pub struct Iter<'a, K: Key, V> {
-    front_entry: Option<(K, std::slice::Iter<'a, V>)>,
+    front_entry: Option<(K, core::slice::Iter<'a, V>)>,
     map_iter: btree_map::Iter<'a, K, Vec<V>>,
-    back_entry: Option<(K, std::slice::Iter<'a, V>)>,
+    back_entry: Option<(K, core::slice::Iter<'a, V>)>,
+    remaining: usize,
 }
 
 impl<'a, K: Key, V> IntoIterator for &'a ContiguousMap<K, V> {
@@ -170,6 +320,7 @@ impl<'a, K: Key, V> IntoIterator for &'a ContiguousMap<K, V> {
             front_entry: None,
             map_iter: self.map.iter(),
             back_entry: None,
+            remaining: self.length,
         }
     }
 }
@@ -178,36 +329,47 @@ impl<'a, K: Key, V> Iterator for Iter<'a, K, V> {
     type Item = (K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        next_impl(
+        let item = next_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k.clone(), v.iter()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
 impl<'a, K: Key, V> DoubleEndedIterator for Iter<'a, K, V> {
     fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
-        next_back_impl(
+        let item = next_back_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k.clone(), v.iter()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
     }
 }
 
 impl<'a, K: Key, V> FusedIterator for Iter<'a, K, V> {}
 
+impl<'a, K: Key, V> ExactSizeIterator for Iter<'a, K, V> {}
+
 /// A mutable iterator over all `(Key, &mut Value)` entries
 /// in a [`ContiguousMap`] in ascending key order.
 ///
 /// See [`ContiguousMap::iter_mut()`].
 pub struct IterMut<'a, K: Key, V> {
-    front_entry: Option<(K, std::slice::IterMut<'a, V>)>,
+    front_entry: Option<(K, core::slice::IterMut<'a, V>)>,
     map_iter: btree_map::IterMut<'a, K, Vec<V>>,
-    back_entry: Option<(K, std::slice::IterMut<'a, V>)>,
+    back_entry: Option<(K, core::slice::IterMut<'a, V>)>,
+    remaining: usize,
 }
 
 impl<'a, K: Key, V> IntoIterator for &'a mut ContiguousMap<K, V> {
@@ -219,6 +381,7 @@ impl<'a, K: Key, V> IntoIterator for &'a mut ContiguousMap<K, V> {
             front_entry: None,
             map_iter: self.map.iter_mut(),
             back_entry: None,
+            remaining: self.length,
         }
     }
 }
@@ -227,43 +390,163 @@ impl<'a, K: Key, V> Iterator for IterMut<'a, K, V> {
     type Item = (K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        next_impl(
+        let item = next_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k.clone(), v.iter_mut()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
 impl<'a, K: Key, V> DoubleEndedIterator for IterMut<'a, K, V> {
     fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
-        next_back_impl(
+        let item = next_back_impl(
             &mut self.front_entry,
             Some(&mut self.map_iter),
             &mut self.back_entry,
             |(k, v)| (k.clone(), v.iter_mut()),
-        )
+        )?;
+        self.remaining -= 1;
+        Some(item)
     }
 }
 
 impl<'a, K: Key, V> FusedIterator for IterMut<'a, K, V> {}
 
+impl<'a, K: Key, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+/// An iterator over all keys in a [`ContiguousMap`] in ascending order.
+///
+/// See [`ContiguousMap::keys()`].
+pub struct Keys<'a, K: Key, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: Key, V> Keys<'a, K, V> {
+    pub(crate) fn new(map: &'a ContiguousMap<K, V>) -> Self {
+        Self { inner: map.iter() }
+    }
+}
+
+impl<'a, K: Key, V> Iterator for Keys<'a, K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: Key, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+impl<'a, K: Key, V> FusedIterator for Keys<'a, K, V> {}
+
+impl<'a, K: Key, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+/// An iterator over all values in a [`ContiguousMap`] in ascending key order.
+///
+/// See [`ContiguousMap::values()`].
+pub struct Values<'a, K: Key, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: Key, V> Values<'a, K, V> {
+    pub(crate) fn new(map: &'a ContiguousMap<K, V>) -> Self {
+        Self { inner: map.iter() }
+    }
+}
+
+impl<'a, K: Key, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: Key, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Key, V> FusedIterator for Values<'a, K, V> {}
+
+impl<'a, K: Key, V> ExactSizeIterator for Values<'a, K, V> {}
+
+/// A mutable iterator over all values in a [`ContiguousMap`] in ascending key order.
+///
+/// See [`ContiguousMap::values_mut()`].
+pub struct ValuesMut<'a, K: Key, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K: Key, V> ValuesMut<'a, K, V> {
+    pub(crate) fn new(map: &'a mut ContiguousMap<K, V>) -> Self {
+        Self {
+            inner: map.iter_mut(),
+        }
+    }
+}
+
+impl<'a, K: Key, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: Key, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+impl<'a, K: Key, V> FusedIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K: Key, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
 /// An iterator over a range of `(Key, &Value)` entries
 /// in a [`ContiguousMap`] in ascending key order.
 ///
 /// See [`ContiguousMap::range()`].
 pub struct Range<'a, K: Key, V> {
-    front_entry: Option<(K, std::slice::Iter<'a, V>)>,
+    front_entry: Option<(K, core::slice::Iter<'a, V>)>,
     map_iter: Option<btree_map::Range<'a, K, Vec<V>>>,
-    back_entry: Option<(K, std::slice::Iter<'a, V>)>,
+    back_entry: Option<(K, core::slice::Iter<'a, V>)>,
 }
 
 impl<'a, K: Key, V> Range<'a, K, V> {
     pub(crate) fn new(map: &'a ContiguousMap<K, V>, start: Index<K>, end: Index<K>) -> Self {
         if start.key == end.key {
             // entire range is one contiguous region
-            let front_key = start.key.add_usize(start.offset).unwrap();
+            let front_key = start
+                .key
+                .add_usize(start.offset)
+                .expect("all values in the map have a valid key");
             let front_slice = &map.map.get(&start.key).unwrap()[start.offset..=end.offset];
             Self {
                 front_entry: Some((front_key, front_slice.iter())),
@@ -273,7 +556,10 @@ impl<'a, K: Key, V> Range<'a, K, V> {
         } else {
             // range spans multiple contiguous regions
             let mut range = map.map.range(&start.key..=&end.key);
-            let front_key = start.key.add_usize(start.offset).unwrap();
+            let front_key = start
+                .key
+                .add_usize(start.offset)
+                .expect("all values in the map have a valid key");
             let front_slice = &range.next().unwrap().1[start.offset..];
             let back_key = end.key;
             let back_slice = &range.next_back().unwrap().1[..=end.offset];
@@ -325,16 +611,19 @@ impl<'a, K: Key, V> FusedIterator for Range<'a, K, V> {}
 ///
 /// See [`ContiguousMap::range_mut()`].
 pub struct RangeMut<'a, K: Key, V> {
-    front_entry: Option<(K, std::slice::IterMut<'a, V>)>,
+    front_entry: Option<(K, core::slice::IterMut<'a, V>)>,
     map_iter: Option<btree_map::RangeMut<'a, K, Vec<V>>>,
-    back_entry: Option<(K, std::slice::IterMut<'a, V>)>,
+    back_entry: Option<(K, core::slice::IterMut<'a, V>)>,
 }
 
 impl<'a, K: Key, V> RangeMut<'a, K, V> {
     pub(crate) fn new(map: &'a mut ContiguousMap<K, V>, start: Index<K>, end: Index<K>) -> Self {
         if start.key == end.key {
             // entire range is one contiguous region
-            let front_key = start.key.add_usize(start.offset).unwrap();
+            let front_key = start
+                .key
+                .add_usize(start.offset)
+                .expect("all values in the map have a valid key");
             let front_slice = &mut map.map.get_mut(&start.key).unwrap()[start.offset..=end.offset];
             Self {
                 front_entry: Some((front_key, front_slice.iter_mut())),
@@ -344,7 +633,10 @@ impl<'a, K: Key, V> RangeMut<'a, K, V> {
         } else {
             // range spans multiple contiguous regions
             let mut range = map.map.range_mut(&start.key..=&end.key);
-            let front_key = start.key.add_usize(start.offset).unwrap();
+            let front_key = start
+                .key
+                .add_usize(start.offset)
+                .expect("all values in the map have a valid key");
             let front_slice = &mut range.next().unwrap().1[start.offset..];
             let back_key = end.key;
             let back_slice = &mut range.next_back().unwrap().1[..=end.offset];
@@ -397,12 +689,25 @@ impl<'a, K: Key, V> FusedIterator for RangeMut<'a, K, V> {}
 /// See [`ContiguousMap::iter_vec()`].
 pub struct IterVec<K: Key, V> {
     inner: btree_map::IntoIter<K, Vec<V>>,
+    remaining_values: usize,
 }
 
 impl<K: Key, V> IterVec<K, V> {
     pub(crate) fn new(map: ContiguousMap<K, V>) -> Self {
         Self {
             inner: map.map.into_iter(),
+            remaining_values: map.length,
+        }
+    }
+
+    /// Converts this iterator into an owning iterator over the individual
+    /// `(Key, Value)` entries of the contiguous regions it has not yet yielded.
+    pub fn flatten(self) -> IntoIter<K, V> {
+        IntoIter {
+            front_entry: None,
+            map_iter: self.inner,
+            back_entry: None,
+            remaining: self.remaining_values,
         }
     }
 }
@@ -411,18 +716,61 @@ impl<K: Key, V> Iterator for IterVec<K, V> {
     type Item = (K, Vec<V>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
+        let item = self.inner.next()?;
+        self.remaining_values -= item.1.len();
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
 }
 
 impl<K: Key, V> DoubleEndedIterator for IterVec<K, V> {
     fn next_back(&mut self) -> Option<<Self as Iterator>::Item> {
-        self.inner.next_back()
+        let item = self.inner.next_back()?;
+        self.remaining_values -= item.1.len();
+        Some(item)
     }
 }
 
 impl<K: Key, V> FusedIterator for IterVec<K, V> {}
 
+impl<K: Key, V> ExactSizeIterator for IterVec<K, V> {}
+
+/// A view of the contiguous regions of a [`ContiguousMap`]
+/// that can be iterated over in a `for` loop.
+///
+/// Iterating yields the same `(&Key, &[Value])` entries as [`ContiguousMap::iter_slice()`].
+///
+/// See [`ContiguousMap::slices()`].
+pub struct Slices<'a, K: Key, V> {
+    map: &'a ContiguousMap<K, V>,
+}
+
+impl<'a, K: Key, V> Slices<'a, K, V> {
+    pub(crate) fn new(map: &'a ContiguousMap<K, V>) -> Self {
+        Self { map }
+    }
+}
+
+impl<'a, K: Key, V> Clone for Slices<'a, K, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K: Key, V> Copy for Slices<'a, K, V> {}
+
+impl<'a, K: Key, V> IntoIterator for Slices<'a, K, V> {
+    type Item = (&'a K, &'a [V]);
+    type IntoIter = IterSlice<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterSlice::new(self.map)
+    }
+}
+
 /// An iterator over all the contiguous `(&Key, &[Value])` entries
 /// in a [`ContiguousMap`] in ascending key order.
 ///
@@ -445,6 +793,10 @@ impl<'a, K: Key, V> Iterator for IterSlice<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(k, v)| (k, &v[..]))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
 impl<'a, K: Key, V> DoubleEndedIterator for IterSlice<'a, K, V> {
@@ -455,6 +807,8 @@ impl<'a, K: Key, V> DoubleEndedIterator for IterSlice<'a, K, V> {
 
 impl<'a, K: Key, V> FusedIterator for IterSlice<'a, K, V> {}
 
+impl<'a, K: Key, V> ExactSizeIterator for IterSlice<'a, K, V> {}
+
 /// A mutable iterator over all the contiguous `(&Key, &mut [Value])` entries
 /// in a [`ContiguousMap`] in ascending key order.
 ///
@@ -477,6 +831,10 @@ impl<'a, K: Key, V> Iterator for IterSliceMut<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(k, v)| (k, &mut v[..]))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
 impl<'a, K: Key, V> DoubleEndedIterator for IterSliceMut<'a, K, V> {
@@ -487,6 +845,8 @@ impl<'a, K: Key, V> DoubleEndedIterator for IterSliceMut<'a, K, V> {
 
 impl<'a, K: Key, V> FusedIterator for IterSliceMut<'a, K, V> {}
 
+impl<'a, K: Key, V> ExactSizeIterator for IterSliceMut<'a, K, V> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
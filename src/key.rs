@@ -1,4 +1,4 @@
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 /// Trait that must be implemented for all key types
 /// used in a [`ContiguousMap`](crate::ContiguousMap).
@@ -6,6 +6,86 @@ use std::convert::TryInto;
 /// See the blanket implementation for an alternative way of
 /// implementing this trait using the [`ToIndex`] and [`TryFromIndex`]
 /// traits.
+///
+/// # Cloning
+/// Iterating over a [`ContiguousMap`](crate::ContiguousMap) by reference clones
+/// the start key of each contiguous region once.
+/// The key of every other value is produced with [`Key::add_one()`] or [`Key::add_usize()`]
+/// rather than by cloning, so keys that are expensive to clone
+/// do not slow down iteration over large regions.
+///
+/// # Operator Overloads
+/// The operators of a key type are not used by [`ContiguousMap`](crate::ContiguousMap).
+/// For ergonomics a key newtype may implement [`Add<usize>`](core::ops::Add)
+/// in terms of [`Key::add_usize()`] and [`Sub<usize>`](core::ops::Sub),
+/// panicking on overflow.
+/// ```
+/// use contiguous_map::{cmap, Key, ToIndex, TryFromIndex};
+/// use std::ops::{Add, Sub};
+///
+/// #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+/// struct ByteOffset(u64);
+///
+/// impl ToIndex for ByteOffset {
+///     type Index = u64;
+///     fn to_index(&self) -> Self::Index {
+///         self.0
+///     }
+/// }
+///
+/// impl TryFromIndex for ByteOffset {
+///     fn try_from_index(index: Self::Index) -> Option<Self> {
+///         Some(Self(index))
+///     }
+/// }
+///
+/// impl Add<usize> for ByteOffset {
+///     type Output = Self;
+///     fn add(self, num: usize) -> Self {
+///         self.add_usize(num).expect("ByteOffset overflow")
+///     }
+/// }
+///
+/// impl Sub<usize> for ByteOffset {
+///     type Output = Self;
+///     fn sub(self, num: usize) -> Self {
+///         self.sub_usize(num).expect("ByteOffset overflow")
+///     }
+/// }
+///
+/// let start = ByteOffset(8);
+/// assert_eq!(start.add_usize(4), Some(start + 4));
+/// assert_eq!(ByteOffset(4), start - 4);
+///
+/// let map = cmap!(start => 1, 2, 3, 4);
+/// assert_eq!(Some(&4), map.get(start + 3));
+/// ```
+///
+/// Overflowing the key type then panics with a clear message.
+/// ```should_panic
+/// # use contiguous_map::{Key, ToIndex, TryFromIndex};
+/// # use std::ops::Add;
+/// # #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+/// # struct ByteOffset(u64);
+/// # impl ToIndex for ByteOffset {
+/// #     type Index = u64;
+/// #     fn to_index(&self) -> Self::Index {
+/// #         self.0
+/// #     }
+/// # }
+/// # impl TryFromIndex for ByteOffset {
+/// #     fn try_from_index(index: Self::Index) -> Option<Self> {
+/// #         Some(Self(index))
+/// #     }
+/// # }
+/// # impl Add<usize> for ByteOffset {
+/// #     type Output = Self;
+/// #     fn add(self, num: usize) -> Self {
+/// #         self.add_usize(num).expect("ByteOffset overflow")
+/// #     }
+/// # }
+/// let _ = ByteOffset(u64::MAX) + 1;
+/// ```
 pub trait Key
 where
     Self: Sized + Clone + Ord + Eq,
@@ -14,6 +94,10 @@ where
     /// Returns None if there is no adjacent key due to self being the max key.
     fn add_one(&self) -> Option<Self>;
 
+    /// Gets the previous adjacent key.
+    /// Returns None if there is no adjacent key due to self being the min key.
+    fn sub_one(&self) -> Option<Self>;
+
     /// Gets the difference between this key and another one.
     /// Returns None if the difference does not fit in a usize.
     fn difference(&self, smaller: &Self) -> Option<usize>;
@@ -21,6 +105,13 @@ where
     /// Gets the key that is num steps after this key.
     /// Returns None if this overflows the key type.
     fn add_usize(&self, num: usize) -> Option<Self>;
+
+    /// Gets the key that is num steps before this key.
+    /// Returns None if this underflows the key type.
+    ///
+    /// Like [`Key::add_usize()`] this should not take time proportional to `num`,
+    /// as [`Reverse`](core::cmp::Reverse) keys use it to move forwards.
+    fn sub_usize(&self, num: usize) -> Option<Self>;
 }
 
 /// Trait to convert a type to an index that implements the [`Key`] trait.
@@ -98,10 +189,11 @@ where
     Self: Sized + Clone + Ord + Eq + ToIndex<Index = I> + TryFromIndex,
 {
     fn add_one(&self) -> Option<Self> {
-        self.to_index()
-            .add_one()
-            .map(Self::try_from_index)
-            .flatten()
+        self.to_index().add_one().and_then(Self::try_from_index)
+    }
+
+    fn sub_one(&self) -> Option<Self> {
+        self.to_index().sub_one().and_then(Self::try_from_index)
     }
 
     fn difference(&self, smaller: &Self) -> Option<usize> {
@@ -111,11 +203,61 @@ where
     fn add_usize(&self, num: usize) -> Option<Self> {
         self.to_index()
             .add_usize(num)
-            .map(Self::try_from_index)
-            .flatten()
+            .and_then(Self::try_from_index)
+    }
+
+    fn sub_usize(&self, num: usize) -> Option<Self> {
+        self.to_index()
+            .sub_usize(num)
+            .and_then(Self::try_from_index)
     }
 }
 
+/// Optional trait for [`Key`] types with a known lowest and highest key.
+///
+/// This provides helpers that clamp keys into the range of valid keys
+/// instead of returning None on overflow.
+///
+/// # Example
+/// ```
+/// use contiguous_map::BoundedKey;
+///
+/// assert_eq!(u8::MAX, 250u8.saturating_add_usize(10));
+/// assert_eq!(252, 250u8.saturating_add_usize(2));
+/// ```
+pub trait BoundedKey: Key {
+    /// The lowest valid key.
+    const LOWEST: Self;
+
+    /// The highest valid key.
+    const HIGHEST: Self;
+
+    /// Gets the key that is num steps after this key,
+    /// saturating at [`BoundedKey::HIGHEST`] instead of overflowing.
+    fn saturating_add_usize(&self, num: usize) -> Self {
+        self.add_usize(num).unwrap_or(Self::HIGHEST)
+    }
+
+    /// Clamps this key to be between [`BoundedKey::LOWEST`]
+    /// and [`BoundedKey::HIGHEST`] inclusive.
+    fn clamp_to_valid(self) -> Self {
+        self.clamp(Self::LOWEST, Self::HIGHEST)
+    }
+}
+
+macro_rules! bounded_key_impl {
+    ($($type:ty),*) => {
+        $(
+            impl BoundedKey for $type {
+                const LOWEST: Self = <$type>::MIN;
+                const HIGHEST: Self = <$type>::MAX;
+            }
+        )*
+    };
+}
+
+bounded_key_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char);
+
 macro_rules! unsigned_key_impl {
     ($type:ty) => {
         impl Key for $type {
@@ -123,15 +265,22 @@ macro_rules! unsigned_key_impl {
                 self.checked_add(1)
             }
 
+            fn sub_one(&self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
             fn difference(&self, smaller: &Self) -> Option<usize> {
                 self.checked_sub(*smaller)
-                    .map(|value| value.try_into().ok())
-                    .flatten()
+                    .and_then(|value| value.try_into().ok())
             }
 
             fn add_usize(&self, num: usize) -> Option<Self> {
                 self.checked_add(num.try_into().ok()?)
             }
+
+            fn sub_usize(&self, num: usize) -> Option<Self> {
+                self.checked_sub(num.try_into().ok()?)
+            }
         }
     };
 }
@@ -168,6 +317,31 @@ signed_key_impl!(i64, u64);
 signed_key_impl!(i128, u128);
 signed_key_impl!(isize, usize);
 
+macro_rules! nonzero_key_impl {
+    ($type:ty, $inner:ty) => {
+        impl ToIndex for $type {
+            type Index = $inner;
+
+            fn to_index(&self) -> Self::Index {
+                self.get()
+            }
+        }
+
+        impl TryFromIndex for $type {
+            fn try_from_index(index: Self::Index) -> Option<Self> {
+                Self::new(index)
+            }
+        }
+    };
+}
+
+nonzero_key_impl!(core::num::NonZeroU8, u8);
+nonzero_key_impl!(core::num::NonZeroU16, u16);
+nonzero_key_impl!(core::num::NonZeroU32, u32);
+nonzero_key_impl!(core::num::NonZeroU64, u64);
+nonzero_key_impl!(core::num::NonZeroU128, u128);
+nonzero_key_impl!(core::num::NonZeroUsize, usize);
+
 impl ToIndex for char {
     type Index = u32;
 
@@ -196,6 +370,60 @@ impl TryFromIndex for char {
     }
 }
 
+#[cfg(feature = "std")]
+impl ToIndex for std::net::Ipv4Addr {
+    type Index = u32;
+
+    fn to_index(&self) -> Self::Index {
+        u32::from(*self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFromIndex for std::net::Ipv4Addr {
+    fn try_from_index(index: Self::Index) -> Option<Self> {
+        // every u32 is a valid address
+        Some(Self::from(index))
+    }
+}
+
+/// Reverses the order of keys so that a [`ContiguousMap`](crate::ContiguousMap)
+/// iterates in descending order of the inner keys.
+///
+/// The key after `Reverse(k)` is `Reverse(k - 1)`.
+///
+/// # Example
+/// ```
+/// use contiguous_map::cmap;
+/// use std::cmp::Reverse;
+///
+/// let map = cmap!(Reverse(5u32) => 'a', 'b', 'c');
+/// assert_eq!(Some(&'c'), map.get(Reverse(3)));
+/// let keys: Vec<u32> = map.keys().map(|key| key.0).collect();
+/// assert_eq!(vec![5, 4, 3], keys);
+/// ```
+impl<K: Key> Key for core::cmp::Reverse<K> {
+    fn add_one(&self) -> Option<Self> {
+        self.0.sub_one().map(Self)
+    }
+
+    fn sub_one(&self) -> Option<Self> {
+        self.0.add_one().map(Self)
+    }
+
+    fn difference(&self, smaller: &Self) -> Option<usize> {
+        smaller.0.difference(&self.0)
+    }
+
+    fn add_usize(&self, num: usize) -> Option<Self> {
+        self.0.sub_usize(num).map(Self)
+    }
+
+    fn sub_usize(&self, num: usize) -> Option<Self> {
+        self.0.add_usize(num).map(Self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -232,6 +460,29 @@ mod test {
         assert_eq!(None, i8::MAX.add_one());
     }
 
+    #[test]
+    fn u8_sub_one() {
+        assert_eq!(Some(4), 5u8.sub_one());
+        assert_eq!(Some(0), 1u8.sub_one());
+        assert_eq!(None, 0u8.sub_one());
+        assert_eq!(Some(u8::MAX - 1), u8::MAX.sub_one());
+    }
+
+    #[test]
+    fn i8_sub_one() {
+        assert_eq!(-101, (-100i8).sub_one().unwrap());
+        assert_eq!(-1, 0i8.sub_one().unwrap());
+        assert_eq!(Some(i8::MIN), (i8::MIN + 1).sub_one());
+        assert_eq!(None, i8::MIN.sub_one());
+    }
+
+    #[test]
+    fn char_sub_one() {
+        assert_eq!(Some('a'), 'b'.sub_one());
+        assert_eq!(Some('\u{D7FF}'), '\u{E000}'.sub_one());
+        assert_eq!(None, '\0'.sub_one());
+    }
+
     #[test]
     fn i8_difference() {
         for i in i8::MIN..=i8::MAX {
@@ -284,6 +535,31 @@ mod test {
         }
     }
 
+    #[test]
+    fn add_usize_to_max() {
+        assert_eq!(Some(u16::MAX), 0u16.add_usize(u16::MAX as usize));
+        assert_eq!(Some(u32::MAX), 1u32.add_usize(u32::MAX as usize - 1));
+        assert_eq!(Some(usize::MAX), 0usize.add_usize(usize::MAX));
+        assert_eq!(Some(i8::MAX), i8::MIN.add_usize(255));
+        assert_eq!(None, i8::MIN.add_usize(256));
+        assert_eq!(Some(i16::MAX), i16::MIN.add_usize(u16::MAX as usize));
+        assert_eq!(Some(i32::MAX), (-1i32).add_usize(i32::MAX as usize + 1));
+        assert_eq!(Some(isize::MAX), isize::MIN.add_usize(usize::MAX));
+    }
+
+    #[test]
+    fn char_add_usize_to_max() {
+        let max_index = char::MAX.to_index() as usize;
+        assert_eq!(Some(char::MAX), '\0'.add_usize(max_index));
+        assert_eq!(None, '\0'.add_usize(max_index + 1));
+        assert_eq!(
+            Some(char::MAX),
+            'a'.add_usize(max_index - 'a'.to_index() as usize)
+        );
+        // crossing the surrogate gap
+        assert_eq!(Some('\u{E000}'), '\u{D7FF}'.add_usize(1));
+    }
+
     // test type that uses ToIndex and TryFromIndex to implement Key
     #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
     struct LessThan100(u8);
@@ -311,6 +587,63 @@ mod test {
         }
     }
 
+    impl BoundedKey for LessThan100 {
+        const LOWEST: Self = Self(0);
+        const HIGHEST: Self = Self(99);
+    }
+
+    #[test]
+    fn bounded_u8_sub_one() {
+        assert_eq!(
+            LessThan100::new(0).unwrap(),
+            LessThan100::new(1).unwrap().sub_one().unwrap()
+        );
+        assert_eq!(
+            LessThan100::new(98).unwrap(),
+            LessThan100::new(99).unwrap().sub_one().unwrap()
+        );
+        assert_eq!(None, LessThan100::new(0).unwrap().sub_one());
+    }
+
+    #[test]
+    fn bounded_u8_saturating_add_usize() {
+        assert_eq!(
+            LessThan100::new(30).unwrap(),
+            LessThan100::new(10).unwrap().saturating_add_usize(20)
+        );
+        assert_eq!(
+            LessThan100::HIGHEST,
+            LessThan100::new(90).unwrap().saturating_add_usize(20)
+        );
+        assert_eq!(
+            LessThan100::HIGHEST,
+            LessThan100::new(1)
+                .unwrap()
+                .saturating_add_usize(usize::MAX)
+        );
+    }
+
+    #[test]
+    fn bounded_u8_clamp_to_valid() {
+        assert_eq!(LessThan100(50), LessThan100(50).clamp_to_valid());
+        // overflowing add on the inner value
+        assert_eq!(LessThan100::HIGHEST, LessThan100(90 + 20).clamp_to_valid());
+    }
+
+    #[test]
+    fn integer_saturating_add_usize() {
+        assert_eq!(u8::MAX, 250u8.saturating_add_usize(10));
+        assert_eq!(i8::MAX, 120i8.saturating_add_usize(10));
+        assert_eq!(-118, (-128i8).saturating_add_usize(10));
+        assert_eq!(char::MAX, 'a'.saturating_add_usize(usize::MAX));
+    }
+
+    #[test]
+    fn integer_clamp_to_valid() {
+        assert_eq!(5u8, 5u8.clamp_to_valid());
+        assert_eq!(i32::MIN, i32::MIN.clamp_to_valid());
+    }
+
     #[test]
     fn bounded_u8_add_one() {
         assert_eq!(
@@ -351,6 +684,19 @@ mod test {
         assert_eq!(None, LessThan100::new(1).unwrap().add_usize(99));
     }
 
+    #[test]
+    fn bounded_u8_add_usize_to_max() {
+        assert_eq!(
+            LessThan100::new(99).unwrap(),
+            LessThan100::new(0).unwrap().add_usize(99).unwrap()
+        );
+        assert_eq!(
+            LessThan100::new(99).unwrap(),
+            LessThan100::new(98).unwrap().add_usize(1).unwrap()
+        );
+        assert_eq!(None, LessThan100::new(0).unwrap().add_usize(100));
+    }
+
     #[test]
     fn i8_index_traits() {
         let mut prev_i8_index = None;
@@ -372,6 +718,61 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn ipv4_addr_index_traits() {
+        use std::net::Ipv4Addr;
+        let addr = Ipv4Addr::new(192, 168, 0, 255);
+        assert_eq!(0xC0A8_00FF, addr.to_index());
+        assert_eq!(Some(addr), Ipv4Addr::try_from_index(0xC0A8_00FF));
+        assert_eq!(Some(Ipv4Addr::new(192, 168, 1, 0)), addr.add_one());
+        assert_eq!(None, Ipv4Addr::BROADCAST.add_one());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn ipv4_addr_map() {
+        use std::net::Ipv4Addr;
+        let mut map = crate::ContiguousMap::new();
+        map.insert(Ipv4Addr::new(10, 0, 0, 6), 'c');
+        map.insert(Ipv4Addr::new(10, 0, 0, 4), 'a');
+        map.insert(Ipv4Addr::new(10, 0, 0, 7), 'd');
+        map.insert(Ipv4Addr::new(10, 0, 0, 5), 'b');
+        assert_eq!(1, map.num_contiguous_regions());
+        assert_eq!(
+            Some(&['a', 'b', 'c', 'd'][..]),
+            map.get_slice(Ipv4Addr::new(10, 0, 0, 4)..=Ipv4Addr::new(10, 0, 0, 7))
+        );
+    }
+
+    #[test]
+    fn nonzero_u8_index_traits() {
+        use std::num::NonZeroU8;
+        assert_eq!(None, NonZeroU8::try_from_index(0));
+        for i in 1..=u8::MAX {
+            let key = NonZeroU8::new(i).unwrap();
+            assert_eq!(i, key.to_index());
+            assert_eq!(Some(key), NonZeroU8::try_from_index(i));
+        }
+        assert_eq!(None, NonZeroU8::new(u8::MAX).unwrap().add_one());
+    }
+
+    #[test]
+    fn nonzero_u32_map() {
+        use std::num::NonZeroU32;
+        let key = |i| NonZeroU32::new(i).unwrap();
+        let mut map = crate::ContiguousMap::new();
+        map.insert(key(2), 12);
+        map.insert(key(1), 11);
+        map.insert(key(3), 13);
+        assert_eq!(1, map.num_contiguous_regions());
+        assert_eq!(Some(&11), map.get(key(1)));
+        assert_eq!(Some(&12), map.get(key(2)));
+        assert_eq!(Some(&13), map.get(key(3)));
+        assert_eq!(None, map.get(key(4)));
+        assert_eq!(Some(&[11, 12, 13][..]), map.get_slice(key(1)..));
+    }
+
     #[test]
     fn char_index_traits() {
         let mut prev_u32_index = None;
@@ -402,4 +803,107 @@ mod test {
         let out_of_bounds_index = prev_u32_index.unwrap().1 + 1;
         assert_eq!(None, char::try_from_index(out_of_bounds_index));
     }
+
+    #[test]
+    fn u8_sub_usize() {
+        assert_eq!(Some(5), 10u8.sub_usize(5));
+        assert_eq!(Some(0), 10u8.sub_usize(10));
+        assert_eq!(None, 10u8.sub_usize(11));
+        assert_eq!(None, 10u8.sub_usize(usize::MAX));
+    }
+
+    #[test]
+    fn i8_sub_usize() {
+        assert_eq!(Some(-5), 5i8.sub_usize(10));
+        assert_eq!(Some(i8::MIN), i8::MAX.sub_usize(255));
+        assert_eq!(None, i8::MAX.sub_usize(256));
+    }
+
+    #[test]
+    fn reverse_key() {
+        use core::cmp::Reverse;
+        assert_eq!(Some(Reverse(4u8)), Reverse(5u8).add_one());
+        assert_eq!(None, Reverse(0u8).add_one());
+        assert_eq!(Some(Reverse(6u8)), Reverse(5u8).sub_one());
+        assert_eq!(None, Reverse(u8::MAX).sub_one());
+        assert_eq!(Some(3), Reverse(2u8).difference(&Reverse(5u8)));
+        assert_eq!(None, Reverse(5u8).difference(&Reverse(2u8)));
+        assert_eq!(Some(Reverse(2u8)), Reverse(5u8).add_usize(3));
+        assert_eq!(None, Reverse(5u8).add_usize(6));
+        assert_eq!(Some(Reverse(8u8)), Reverse(5u8).sub_usize(3));
+    }
+
+    #[test]
+    fn reverse_map_descending() {
+        use core::cmp::Reverse;
+        let mut map = crate::ContiguousMap::new();
+        map.insert(Reverse(3u32), 'c');
+        map.insert(Reverse(5u32), 'e');
+        map.insert(Reverse(10u32), 'j');
+        assert_eq!(3, map.num_contiguous_regions());
+        map.insert(Reverse(4u32), 'd');
+        assert_eq!(2, map.num_contiguous_regions());
+        assert_eq!(Some(&['e', 'd', 'c'][..]), map.get_slice(Reverse(5u32)..));
+        let items: Vec<(u32, char)> = map.iter().map(|(key, value)| (key.0, *value)).collect();
+        assert_eq!(vec![(10, 'j'), (5, 'e'), (4, 'd'), (3, 'c')], items);
+        let items: Vec<(u32, char)> = map
+            .iter()
+            .rev()
+            .map(|(key, value)| (key.0, *value))
+            .collect();
+        assert_eq!(vec![(3, 'c'), (4, 'd'), (5, 'e'), (10, 'j')], items);
+    }
+
+    #[test]
+    fn reverse_map_max_key() {
+        use core::cmp::Reverse;
+        let mut map = crate::ContiguousMap::new();
+        map.insert_slice(Reverse(1u8), &[1, 0, -1]);
+        assert_eq!(Some(Reverse(0u8)), map.max_key());
+        assert_eq!(2, map.len());
+    }
+
+    /// Key implemented by hand rather than through [`ToIndex`].
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct CustomKey(u64);
+
+    impl Key for CustomKey {
+        fn add_one(&self) -> Option<Self> {
+            self.0.checked_add(1).map(Self)
+        }
+
+        fn sub_one(&self) -> Option<Self> {
+            self.0.checked_sub(1).map(Self)
+        }
+
+        fn difference(&self, smaller: &Self) -> Option<usize> {
+            self.0.difference(&smaller.0)
+        }
+
+        fn add_usize(&self, num: usize) -> Option<Self> {
+            self.0.add_usize(num).map(Self)
+        }
+
+        fn sub_usize(&self, num: usize) -> Option<Self> {
+            self.0.sub_usize(num).map(Self)
+        }
+    }
+
+    #[test]
+    fn reverse_custom_key_large_offset() {
+        use core::cmp::Reverse;
+        let key = Reverse(CustomKey(1 << 40));
+        assert_eq!(
+            Some(Reverse(CustomKey((1 << 40) - (1 << 33)))),
+            key.add_usize(1 << 33)
+        );
+        assert_eq!(None, key.add_usize((1 << 40) + 1));
+
+        let mut map = crate::ContiguousMap::new();
+        map.insert(key.clone(), 'a');
+        map.insert(Reverse(CustomKey(0)), 'b');
+        map.clear_with_len(key, 1 << 33);
+        assert_eq!(1, map.len());
+        assert_eq!(Some(&'b'), map.get(Reverse(CustomKey(0))));
+    }
 }
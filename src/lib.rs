@@ -1,21 +1,49 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use std::{
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::{
     borrow::Borrow,
     cmp::Ordering,
-    collections::BTreeMap,
-    ops::{Bound, RangeBounds},
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    ops::{Bound, RangeBounds, RangeInclusive},
 };
 
 mod macros;
 
+mod clamped;
+pub use clamped::Clamped;
+mod descending;
+pub use descending::Descending;
+mod dyn_key;
+pub use dyn_key::{BoxedKey, DynKey};
+mod entry;
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+mod error;
+pub use error::{CounterFullError, OccupiedError, PushError, ShiftError};
+
 mod iter;
-pub use iter::{IntoIter, Iter, IterMut, IterSlice, IterSliceMut, IterVec, Range, RangeMut};
+pub use iter::{
+    DrainRange, ExtractIf, IntoIter, Iter, IterMut, IterSlice, IterSliceMut, IterVec, Keys, Range,
+    RangeMut, Slices, Values, ValuesMut,
+};
 mod key;
-pub use key::{Key, ToIndex, TryFromIndex};
+pub use key::{BoundedKey, Key, ToIndex, TryFromIndex};
 mod range_bounds;
 pub use range_bounds::InclusiveStartRangeBounds;
+mod seg_key;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub use seg_key::SegKey;
+mod shrink_policy;
+pub use shrink_policy::ShrinkPolicy;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
 
 /// An index into a ContiguousMap.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -24,13 +52,105 @@ struct Index<K: Key> {
     offset: usize,
 }
 
+/// Puts the regions kept by [`ContiguousMap::retain_regions()`] back into the map
+/// when dropped, including when unwinding from a panic.
+struct RetainRegionsGuard<'a, K: Key, V> {
+    map: &'a mut ContiguousMap<K, V>,
+    retained: BTreeMap<K, Vec<V>>,
+}
+
+impl<'a, K: Key, V> Drop for RetainRegionsGuard<'a, K, V> {
+    fn drop(&mut self) {
+        // every retained region is before and not adjacent to the unexamined regions
+        self.map.map.append(&mut self.retained);
+        self.map.length = self.map.map.values().map(Vec::len).sum();
+    }
+}
+
+/// Filters the values of one contiguous region that has been detached from a map.
+///
+/// The kept values and the values not yet examined are put back into the map when dropped,
+/// including when unwinding from a panic in the predicate,
+/// and the cached length is decremented as each value is rejected.
+struct RegionFilter<'a, K: Key, V> {
+    map: &'a mut ContiguousMap<K, V>,
+    run: Option<(K, Vec<V>)>,
+    tail_key: Option<K>,
+    tail: vec::IntoIter<V>,
+}
+
+impl<'a, K: Key, V> RegionFilter<'a, K, V> {
+    /// Detaches the region starting at `start_key` from the map.
+    fn new(map: &'a mut ContiguousMap<K, V>, start_key: K) -> Self {
+        let vec = map
+            .map
+            .remove(&start_key)
+            .expect("the region to filter is in the map");
+        Self {
+            map,
+            run: None,
+            tail_key: Some(start_key),
+            tail: vec.into_iter(),
+        }
+    }
+
+    /// Calls `pred` with each value of the region in ascending key order.
+    /// Values for which it returns false are removed and passed to `reject`.
+    fn filter<F, R>(mut self, mut pred: F, mut reject: R)
+    where
+        F: FnMut(K, &mut V) -> bool,
+        R: FnMut(K, V),
+    {
+        // the value is examined in place so that it is put back if `pred` panics
+        while let Some(value) = self.tail.as_mut_slice().first_mut() {
+            let key = self
+                .tail_key
+                .clone()
+                .expect("all values in the map have a valid key");
+            let keep = pred(key.clone(), value);
+            let value = self.tail.next().expect("the value was just examined");
+            self.tail_key = key.add_one();
+            if keep {
+                self.run
+                    .get_or_insert_with(|| (key, Vec::new()))
+                    .1
+                    .push(value);
+            } else {
+                self.map.length -= 1;
+                if let Some((run_key, run_vec)) = self.run.take() {
+                    self.map.map.insert(run_key, run_vec);
+                }
+                reject(key, value);
+            }
+        }
+    }
+}
+
+impl<'a, K: Key, V> Drop for RegionFilter<'a, K, V> {
+    fn drop(&mut self) {
+        // the kept run is always adjacent to the unexamined values
+        let tail = core::mem::replace(&mut self.tail, Vec::new().into_iter());
+        match (self.run.take(), self.tail_key.take()) {
+            (Some((run_key, mut run_vec)), _) => {
+                run_vec.extend(tail);
+                self.map.map.insert(run_key, run_vec);
+            }
+            (None, Some(tail_key)) if tail.len() != 0 => {
+                self.map.map.insert(tail_key, tail.collect());
+            }
+            _ => {}
+        }
+        self.map.debug_check_length();
+    }
+}
+
 /// An ordered, associative container like [`std::collections::BTreeMap`].
 /// Additionally stores values with adjacent keys contiguously so they may
 /// be accessed as a slice.
-#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContiguousMap<K: Key, V> {
     map: BTreeMap<K, Vec<V>>,
     length: usize,
+    shrink_policy: ShrinkPolicy,
 }
 
 impl<K: Key, V> ContiguousMap<K, V> {
@@ -39,17 +159,133 @@ impl<K: Key, V> ContiguousMap<K, V> {
         Self {
             map: BTreeMap::new(),
             length: 0,
+            shrink_policy: ShrinkPolicy::default(),
+        }
+    }
+
+    /// Makes a new ContiguousMap from a vector of optional values.
+    ///
+    /// Each value in the vector has a key that is its index in the vector
+    /// plus `start`.
+    /// Only the `Some` values are inserted into the map.
+    /// Values whose key would overflow the key type are ignored.
+    pub fn from_option_vec(start: K, vec: Vec<Option<V>>) -> Self {
+        let mut map = Self::new();
+        let mut key = start;
+        for value in vec {
+            if let Some(value) = value {
+                map.insert(key.clone(), value);
+            }
+            key = match key.add_one() {
+                Some(k) => k,
+                None => break,
+            };
+        }
+        map
+    }
+
+    /// Makes a new ContiguousMap with clones of a value for every key in the given inclusive ranges.
+    ///
+    /// Overlapping and adjacent ranges are merged into a single contiguous region.
+    /// Ranges with a start after their end are ignored.
+    /// This is the inverse of [`ContiguousMap::to_range_set()`].
+    pub fn from_ranges<I: IntoIterator<Item = RangeInclusive<K>>>(ranges: I, fill: V) -> Self
+    where
+        V: Clone,
+    {
+        let mut map = Self::new();
+        for range in ranges {
+            let (mut key, end) = range.into_inner();
+            if key > end {
+                continue;
+            }
+            loop {
+                map.insert(key.clone(), fill.clone());
+                if key == end {
+                    break;
+                }
+                key = key
+                    .add_one()
+                    .expect("key is before the end of the range so the next key exists");
+            }
         }
+        map
+    }
+
+    /// Makes a new ContiguousMap with a single contiguous region covering a range of keys,
+    /// calling a function to make the value for each key in ascending key order.
+    ///
+    /// Ranges without an inclusive start like `..5` are not accepted.
+    /// Ranges without an end like `5..` are not filled, as they could cover far more keys
+    /// than can be stored, so they produce an empty map without calling the function.
+    /// Empty ranges also produce an empty map.
+    /// ```
+    /// use contiguous_map::ContiguousMap;
+    ///
+    /// let map = ContiguousMap::from_range_with(0u8..5, |key| key as i32 * 10);
+    /// assert_eq!(Some(&[0, 10, 20, 30, 40][..]), map.get_slice(0..));
+    /// ```
+    pub fn from_range_with<R: InclusiveStartRangeBounds<K>, F: FnMut(K) -> V>(
+        range: R,
+        mut f: F,
+    ) -> Self {
+        let start = range.start_bound();
+        let in_range = |key: &K| match range.end_bound() {
+            Bound::Included(end) => key <= end,
+            Bound::Excluded(end) => key < end,
+            Bound::Unbounded => false,
+        };
+        let mut vec = Vec::new();
+        let mut next = Some(start.clone());
+        while let Some(key) = next.filter(|key| in_range(key)) {
+            next = key.add_one();
+            vec.push(f(key));
+        }
+
+        let mut map = Self::new();
+        if !vec.is_empty() {
+            map.length = vec.len();
+            map.map.insert(start.clone(), vec);
+        }
+        map
+    }
+
+    /// Gets the policy for reclaiming the unused capacity of contiguous regions.
+    pub fn shrink_policy(&self) -> ShrinkPolicy {
+        self.shrink_policy
+    }
+
+    /// Sets the policy for reclaiming the unused capacity of contiguous regions.
+    ///
+    /// The policy is applied to each region that has values removed from it by
+    /// [`ContiguousMap::remove()`] and [`ContiguousMap::clear_range()`].
+    pub fn set_shrink_policy(&mut self, policy: ShrinkPolicy) {
+        self.shrink_policy = policy;
     }
 
     /// Gets the number of entries in this map.
     ///
     /// This is the total number of values in the map, not the number of contiguous regions.
     /// For the number of contiguous regions use [`ContiguousMap::num_contiguous_regions()`]
+    ///
+    /// This count is cached, so this takes O(1) time.
+    /// Values with a zero sized type could make the count overflow a usize,
+    /// which panics in debug builds.
+    /// Use [`ContiguousMap::try_len()`] to detect this instead.
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Counts the number of entries in this map by summing the lengths of its contiguous regions.
+    /// Returns None if the count overflows a usize.
+    ///
+    /// Unlike [`ContiguousMap::len()`] this takes O(n) time in the number of regions.
+    pub fn try_len(&self) -> Option<usize> {
+        self.map
+            .values()
+            .try_fold(0usize, |total, vec| total.checked_add(vec.len()))
+    }
+
     /// Gets if this map is empty.
     pub fn is_empty(&self) -> bool {
         // as no empty entries are allowed in the map we do not
@@ -69,6 +305,127 @@ impl<K: Key, V> ContiguousMap<K, V> {
         self.map.len()
     }
 
+    /// Converts this map into a map with the same keys and contiguous regions
+    /// by applying a function to every value in ascending key order.
+    pub fn map_values<W, F: FnMut(V) -> W>(self, mut f: F) -> ContiguousMap<K, W> {
+        ContiguousMap {
+            map: self
+                .map
+                .into_iter()
+                .map(|(key, vec)| (key, vec.into_iter().map(&mut f).collect()))
+                .collect(),
+            length: self.length,
+            shrink_policy: self.shrink_policy,
+        }
+    }
+
+    /// Converts this map into a map with the same keys and contiguous regions
+    /// by applying a fallible function to every value in ascending key order.
+    ///
+    /// Stops at and returns the first error returned by the function.
+    pub fn try_map_values<W, E, F: FnMut(V) -> Result<W, E>>(
+        self,
+        mut f: F,
+    ) -> Result<ContiguousMap<K, W>, E> {
+        let map = self
+            .map
+            .into_iter()
+            .map(|(key, vec)| Ok((key, vec.into_iter().map(&mut f).collect::<Result<_, E>>()?)))
+            .collect::<Result<_, E>>()?;
+        Ok(ContiguousMap {
+            map,
+            length: self.length,
+            shrink_policy: self.shrink_policy,
+        })
+    }
+
+    /// Gets the number of keys between the first and last keys in this map inclusive.
+    ///
+    /// Returns None if this map is empty or if the number of keys does not fit in a usize.
+    pub fn key_span(&self) -> Option<usize> {
+        let first = self.first()?;
+        let last = self.last()?;
+        last.key
+            .difference(&first.key)?
+            .checked_add(last.offset)?
+            .checked_add(1)
+    }
+
+    /// Gets the fraction of the keys between the first and last keys
+    /// in this map inclusive that have a value.
+    ///
+    /// Returns `1.0` if this map is a single contiguous region.
+    /// Returns None if this map is empty or if its
+    /// [`ContiguousMap::key_span()`] does not fit in a usize.
+    pub fn coverage_ratio(&self) -> Option<f64> {
+        Some(self.length as f64 / self.key_span()? as f64)
+    }
+
+    /// Gets the nth contiguous region in this map in ascending key order.
+    /// Returns None if `n` is not less than [`ContiguousMap::num_contiguous_regions()`].
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a slice of all of the values in the region.
+    ///
+    /// This walks the regions from the start of the map,
+    /// so it takes O(n) time instead of O(log n).
+    pub fn nth_region(&self, n: usize) -> Option<(K, &[V])> {
+        let entry = self.map.iter().nth(n)?;
+        Some((entry.0.clone(), &entry.1[..]))
+    }
+
+    /// Gets the nth key and a reference to its value in this map in ascending key order.
+    /// Returns None if `n` is not less than [`ContiguousMap::len()`].
+    ///
+    /// Gaps between contiguous regions are skipped,
+    /// so this is equivalent to `map.iter().nth(n)`.
+    /// This walks the regions from the start of the map,
+    /// so it takes O(n) time in the number of regions.
+    pub fn get_nth(&self, n: usize) -> Option<(K, &V)> {
+        let mut n = n;
+        for (key, vec) in self.map.iter() {
+            if n < vec.len() {
+                let key = key
+                    .add_usize(n)
+                    .expect("all values in the map have a valid key");
+                return Some((key, &vec[n]));
+            }
+            n -= vec.len();
+        }
+        None
+    }
+
+    /// Gets the number of values in this map whose keys are less than a key.
+    ///
+    /// This is the inverse of [`ContiguousMap::get_nth()`]:
+    /// if `key` is in this map then `map.get_nth(map.rank(key))` gets its value.
+    /// This walks the regions from the start of the map,
+    /// so it takes O(n) time in the number of regions.
+    pub fn rank<KB: Borrow<K>>(&self, key: KB) -> usize {
+        let key = key.borrow();
+        self.map
+            .range(..key)
+            .map(|(start_key, vec)| match key.difference(start_key) {
+                Some(offset) => offset.min(vec.len()),
+                None => vec.len(),
+            })
+            .sum()
+    }
+
+    /// Gets the inclusive range of keys covered by each contiguous region
+    /// in this map in ascending key order.
+    pub fn to_range_set(&self) -> Vec<RangeInclusive<K>> {
+        self.map
+            .iter()
+            .map(|(key, vec)| {
+                let end = key
+                    .add_usize(vec.len() - 1)
+                    .expect("all values in the map have a valid key");
+                key.clone()..=end
+            })
+            .collect()
+    }
+
     /// Gets an index for the first entry in this map.
     /// Returns None if this map is empty.
     fn first(&self) -> Option<Index<K>> {
@@ -89,6 +446,60 @@ impl<K: Key, V> ContiguousMap<K, V> {
         })
     }
 
+    /// Gets the first contiguous region in this map.
+    /// Returns None if this map is empty.
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a slice of all of the values in the region.
+    pub fn first_region(&self) -> Option<(K, &[V])> {
+        let (key, vec) = self.map.iter().next()?;
+        Some((key.clone(), &vec[..]))
+    }
+
+    /// Gets the last contiguous region in this map.
+    /// Returns None if this map is empty.
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a slice of all of the values in the region.
+    pub fn last_region(&self) -> Option<(K, &[V])> {
+        let (key, vec) = self.map.iter().next_back()?;
+        Some((key.clone(), &vec[..]))
+    }
+
+    /// Gets the smallest key in this map.
+    /// Returns None if this map is empty.
+    pub fn min_key(&self) -> Option<K> {
+        self.map.keys().next().cloned()
+    }
+
+    /// Gets the largest key in this map.
+    /// Returns None if this map is empty.
+    pub fn max_key(&self) -> Option<K> {
+        let (key, vec) = self.map.iter().next_back()?;
+        let key = key
+            .add_usize(vec.len() - 1)
+            .expect("all values in the map have a valid key");
+        Some(key)
+    }
+
+    /// Gets the first key and a reference to its value in this map.
+    /// Returns None if this map is empty.
+    pub fn first_key_value(&self) -> Option<(K, &V)> {
+        let (key, vec) = self.map.iter().next()?;
+        Some((key.clone(), &vec[0]))
+    }
+
+    /// Gets the last key and a reference to its value in this map.
+    /// Returns None if this map is empty.
+    pub fn last_key_value(&self) -> Option<(K, &V)> {
+        let (key, vec) = self.map.iter().next_back()?;
+        let offset = vec.len() - 1;
+        let key = key
+            .add_usize(offset)
+            .expect("all values in the map have a valid key");
+        Some((key, &vec[offset]))
+    }
+
     /// Gets an index for a key.  Returns None if the key is not in this map.
     fn find(&self, key: &K) -> Option<Index<K>> {
         let entry = self.map.range(..=key).next_back()?;
@@ -107,22 +518,19 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// Returns None if all keys in the map are greater than the given key.
     fn find_at_most(&self, key: &K) -> Option<Index<K>> {
         let entry = self.map.range(..=key).next_back()?;
-        let offset = key.difference(entry.0)?;
+        // the difference only fails to fit in a usize when
+        // the key is well past the end of the entry's vector
+        let offset = key.difference(entry.0).unwrap_or(usize::MAX);
         Some(Index {
             key: entry.0.clone(),
-            offset: std::cmp::min(offset, entry.1.len() - 1),
+            offset: core::cmp::min(offset, entry.1.len() - 1),
         })
     }
 
     /// Gets an index for the largest key that is less than the given key.
     /// Returns None if all keys in the map are greater than or equal to the given key.
     fn find_less(&self, key: &K) -> Option<Index<K>> {
-        let entry = self.map.range(..key).next_back()?;
-        let offset = key.difference(entry.0)?;
-        Some(Index {
-            key: entry.0.clone(),
-            offset: std::cmp::min(offset - 1, entry.1.len() - 1),
-        })
+        self.find_at_most(&key.sub_one()?)
     }
 
     /// Gets an index for the smallest key that is at least the given key.
@@ -165,6 +573,37 @@ impl<K: Key, V> ContiguousMap<K, V> {
         }
     }
 
+    /// Asserts that the cached length equals the number of values in this map.
+    ///
+    /// This visits every region, so it is only compiled into this crate's tests
+    /// and builds with the `test-util` feature.
+    fn debug_check_length(&self) {
+        #[cfg(any(test, feature = "test-util"))]
+        assert_eq!(
+            self.length,
+            self.map.values().map(Vec::len).sum::<usize>(),
+            "cached length does not match the number of values in the map"
+        );
+    }
+
+    /// Overwrites the cached length without changing the values in this map.
+    #[cfg(test)]
+    pub(crate) fn set_cached_length(&mut self, length: usize) {
+        self.length = length;
+    }
+
+    /// Makes a map directly from regions without merging adjacent regions.
+    /// Used to test repairing maps that are not in their canonical form.
+    #[cfg(test)]
+    pub(crate) fn from_raw_regions<I: IntoIterator<Item = (K, Vec<V>)>>(regions: I) -> Self {
+        let map: BTreeMap<K, Vec<V>> = regions.into_iter().collect();
+        Self {
+            length: map.values().map(Vec::len).sum(),
+            map,
+            shrink_policy: ShrinkPolicy::default(),
+        }
+    }
+
     /// Inserts a value into a map with a given key.
     /// Returns the old value for this key if one existed.
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
@@ -175,7 +614,7 @@ impl<K: Key, V> ContiguousMap<K, V> {
                     Ordering::Less => {
                         // overwriting a value in insertion_entry
                         let mut value = value;
-                        std::mem::swap(&mut value, &mut insertion_entry.1[index]);
+                        core::mem::swap(&mut value, &mut insertion_entry.1[index]);
                         return Some(value);
                     }
                     Ordering::Equal => {
@@ -192,6 +631,7 @@ impl<K: Key, V> ContiguousMap<K, V> {
                             }
                         }
                         self.length += 1;
+                        self.debug_check_length();
                         return None;
                     }
                     Ordering::Greater => {
@@ -211,21 +651,441 @@ impl<K: Key, V> ContiguousMap<K, V> {
         }
         self.map.insert(key, vec);
         self.length += 1;
+        self.debug_check_length();
         None
     }
 
+    /// Inserts a value into a map with a given key only if the key is vacant.
+    /// Returns a mutable reference to the inserted value.
+    ///
+    /// If the key is occupied nothing is changed and an [`OccupiedError`] is returned
+    /// containing the value that was not inserted and the existing value.
+    ///
+    /// Like [`ContiguousMap::insert()`] this merges the value with any adjacent contiguous regions.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<&mut V, OccupiedError<'_, V>> {
+        match self.entry(key) {
+            Entry::Vacant(entry) => Ok(entry.insert(value)),
+            Entry::Occupied(entry) => Err(OccupiedError {
+                existing: entry.into_mut(),
+                value,
+            }),
+        }
+    }
+
+    /// Inserts a value with a key that is greater than every key in this map.
+    ///
+    /// If the key is one after the largest key the value is appended to the
+    /// last contiguous region in amortized O(1) time.
+    /// Returns a [`PushError`] containing the value and leaves this map unchanged
+    /// if the key is not greater than the largest key in this map.
+    pub fn push_back(&mut self, key: K, value: V) -> Result<(), PushError<V>> {
+        if let Some((last_key, vec)) = self.map.iter_mut().next_back() {
+            if key <= *last_key {
+                return Err(PushError(value));
+            }
+            // a difference too large for a usize is past the end of the region
+            match key.difference(last_key) {
+                Some(offset) if offset == vec.len() => {
+                    vec.push(value);
+                    self.length += 1;
+                    self.debug_check_length();
+                    return Ok(());
+                }
+                Some(offset) if offset < vec.len() => return Err(PushError(value)),
+                _ => {}
+            }
+        }
+        self.map.insert(key, vec![value]);
+        self.length += 1;
+        self.debug_check_length();
+        Ok(())
+    }
+
+    /// Inserts a value with the key that is one after the largest key in this map.
+    /// If this map is empty the value is inserted with a key of [`BoundedKey::LOWEST`].
+    ///
+    /// Returns the key the value was inserted with, or [`CounterFullError`]
+    /// if the largest key in this map has no next adjacent key.
+    pub fn insert_next(&mut self, value: V) -> Result<K, CounterFullError>
+    where
+        K: BoundedKey,
+    {
+        let key = match self.map.iter_mut().next_back() {
+            Some((start_key, vec)) => {
+                // the next key is always adjacent to the last region
+                let key = start_key.add_usize(vec.len()).ok_or(CounterFullError)?;
+                vec.push(value);
+                key
+            }
+            None => {
+                let key = K::LOWEST;
+                self.map.insert(key.clone(), vec![value]);
+                key
+            }
+        };
+        self.length += 1;
+        self.debug_check_length();
+        Ok(key)
+    }
+
+    /// Inserts every entry from another map into this map.
+    ///
+    /// If a key is in both maps then `combine` is called with
+    /// the existing value and the value from `other`
+    /// instead of overwriting the existing value.
+    pub fn merge_with<F: FnMut(&mut V, V)>(&mut self, other: ContiguousMap<K, V>, mut combine: F) {
+        for (key, value) in other {
+            match self.get_mut(&key) {
+                Some(existing) => combine(existing, value),
+                None => {
+                    self.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Appends values to the end of the last contiguous region of this map.
+    /// Returns the key of the first appended value.
+    ///
+    /// Returns None and leaves this map unchanged if this map is empty
+    /// or if the key of any appended value would overflow the key type.
+    pub fn append_slice(&mut self, values: &[V]) -> Option<K>
+    where
+        V: Clone,
+    {
+        let (start_key, vec) = self.map.iter_mut().next_back()?;
+        let key = start_key.add_usize(vec.len())?;
+        if let Some(last_offset) = values.len().checked_sub(1) {
+            key.add_usize(last_offset)?;
+        }
+        vec.extend_from_slice(values);
+        self.length += values.len();
+        self.debug_check_length();
+        Some(key)
+    }
+
     /// Inserts values into the map from a slice starting at a given key.
+    ///
+    /// Capacity for all of the values is reserved up front in the
+    /// contiguous region that they are inserted into.
     pub fn insert_slice(&mut self, start_key: K, values: &[V])
     where
         V: Clone,
     {
+        let (first, rest) = match values.split_first() {
+            Some(split) => split,
+            None => return,
+        };
+        self.insert(start_key.clone(), first.clone());
+
+        // reserve space for the values that will be appended to the region
+        let (region_key, region) = self
+            .map
+            .range_mut(..=&start_key)
+            .next_back()
+            .expect("region containing start_key was just inserted into");
+        let offset = start_key
+            .difference(region_key)
+            .expect("start_key is within this region");
+        let num_overwritten = region.len() - offset - 1;
+        region.reserve(rest.len().saturating_sub(num_overwritten));
+
         let mut key = start_key;
-        for value in values.iter().cloned() {
-            self.insert(key.clone(), value);
+        for value in rest.iter().cloned() {
             key = match key.add_one() {
                 Some(k) => k,
                 None => return,
             };
+            self.insert(key.clone(), value);
+        }
+    }
+
+    /// Gets the number of keys starting at `start_key` that can hold `len` values
+    /// without overflowing the key type, along with the last of those keys.
+    ///
+    /// `len` must not be zero.
+    fn fit_span(start_key: &K, len: usize) -> (usize, K) {
+        if let Some(end) = start_key.add_usize(len - 1) {
+            return (len, end);
+        }
+        // binary search for the largest offset that does not overflow,
+        // `low` always fits and `high` never does
+        let mut low = 0;
+        let mut high = len - 1;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if start_key.add_usize(mid).is_some() {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let end = start_key
+            .add_usize(low)
+            .expect("the search only keeps offsets that fit");
+        (low + 1, end)
+    }
+
+    /// Inserts values into the map from a slice starting at a given key.
+    ///
+    /// This has the same result as [`ContiguousMap::insert_slice()`],
+    /// but looks up the affected contiguous region once and copies the values
+    /// into it in bulk instead of inserting them one at a time.
+    /// Values whose key would overflow the key type are dropped.
+    pub fn extend_from_slice_at(&mut self, start_key: K, values: &[V])
+    where
+        V: Clone,
+    {
+        if values.is_empty() {
+            return;
+        }
+        let (len, end) = Self::fit_span(&start_key, values.len());
+        // clone before detaching any region so that a panicking clone leaves this map unchanged
+        let mut values = values[..len].to_vec();
+
+        // find the region that contains or ends just before start_key
+        let found = self
+            .map
+            .range(..=&start_key)
+            .next_back()
+            .and_then(|(key, vec)| {
+                let offset = start_key.difference(key)?;
+                if offset <= vec.len() {
+                    Some((key.clone(), offset))
+                } else {
+                    None
+                }
+            });
+        let (region_key, mut vec, offset) = match found {
+            Some((key, offset)) => {
+                let vec = self.map.remove(&key).expect("region was just found");
+                (key, vec, offset)
+            }
+            None => (start_key.clone(), Vec::with_capacity(len), 0),
+        };
+        let num_overwritten = (vec.len() - offset).min(len);
+        // the replaced values are only dropped once the map is whole again
+        let mut replaced: Vec<V> = vec
+            .splice(
+                offset..offset + num_overwritten,
+                values.drain(..num_overwritten),
+            )
+            .collect();
+        vec.append(&mut values);
+        self.length += len - num_overwritten;
+
+        // absorb the regions that overlap or are adjacent to the new values
+        let upper = match end.add_one() {
+            Some(after) => Bound::Included(after),
+            None => Bound::Unbounded,
+        };
+        let following: Vec<K> = self
+            .map
+            .range((Bound::Excluded(start_key), upper))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in following {
+            let mut next = self.map.remove(&key).expect("key was just found");
+            let num_replaced = match end.difference(&key) {
+                Some(diff) => (diff + 1).min(next.len()),
+                None => 0,
+            };
+            self.length -= num_replaced;
+            replaced.extend(next.drain(..num_replaced));
+            vec.append(&mut next);
+        }
+        self.map.insert(region_key, vec);
+        self.debug_check_length();
+        drop(replaced);
+    }
+
+    /// Replaces every value in the span of keys starting at `start_key`
+    /// with the values from a vector.
+    /// Returns the displaced key value pairs in ascending key order.
+    ///
+    /// Afterwards the span holds exactly the new values,
+    /// merged with any adjacent contiguous regions.
+    /// Unlike [`ContiguousMap::insert_slice()`] this does not require `V: Clone`.
+    /// Values whose key would overflow the key type are dropped.
+    pub fn replace_region(&mut self, start_key: K, mut values: Vec<V>) -> Vec<(K, V)> {
+        if values.is_empty() {
+            return Vec::new();
+        }
+        let (len, end) = Self::fit_span(&start_key, values.len());
+        values.truncate(len);
+
+        let displaced = self.remove_range(start_key.clone()..=end.clone());
+        if let Some(next) = end.add_one().and_then(|key| self.map.remove(&key)) {
+            values.extend(next);
+        }
+        match self.map.range_mut(..&start_key).next_back() {
+            Some((key, prev)) if key.add_usize(prev.len()).as_ref() == Some(&start_key) => {
+                prev.extend(values);
+            }
+            _ => {
+                self.map.insert(start_key, values);
+            }
+        }
+        self.length += len;
+        self.debug_check_length();
+        displaced
+    }
+
+    /// Inserts every key value pair from an iterator into this map.
+    ///
+    /// Unlike [`ContiguousMap::insert_slice()`] this does not require `V: Clone`.
+    /// Existing values for the keys are overwritten.
+    pub fn insert_many<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+
+    /// Copies `len` values starting at `src_start` to the keys starting at `dest_start`,
+    /// overwriting any existing values.
+    ///
+    /// Returns false and leaves this map unchanged if any of the source keys
+    /// are not in this map.
+    /// Like [`slice::copy_within()`] overlapping source and destination ranges
+    /// behave as if the source values were first copied to a temporary.
+    /// Values whose destination key would overflow the key type are not copied.
+    pub fn copy_within<KB: Borrow<K>>(&mut self, src_start: KB, dest_start: K, len: usize) -> bool
+    where
+        V: Clone,
+    {
+        if len == 0 {
+            return true;
+        }
+        let values = match self.get_slice_with_len(src_start, len) {
+            Some(slice) => slice.to_vec(),
+            None => return false,
+        };
+        self.insert_slice(dest_start, &values);
+        true
+    }
+
+    /// Gets the entry for a key in this map for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        Entry::new(self, key)
+    }
+
+    /// Applies a function to the value for a key.
+    /// If the key is not in this map then the value created by `default`
+    /// is inserted first.
+    pub fn modify_or_insert<F, G>(&mut self, key: K, default: G, modify: F)
+    where
+        F: FnOnce(&mut V),
+        G: FnOnce() -> V,
+    {
+        modify(self.entry(key).or_insert_with(default));
+    }
+
+    /// Fills every gap between the first and last keys of this map with clones of a value,
+    /// leaving this map with at most one contiguous region.
+    ///
+    /// Returns the inclusive `(start, end)` key ranges of the gaps that were filled
+    /// in ascending order.
+    ///
+    /// # Panics
+    /// Panics if a gap contains more keys than fit in a usize.
+    /// This map is left unchanged if this function panics.
+    pub fn fill_all_gaps(&mut self, fill: V) -> Vec<(K, K)>
+    where
+        V: Clone,
+    {
+        // make every fill value before touching the map so a panic leaves it unchanged
+        let mut gaps = Vec::new();
+        let mut regions = self.map.iter();
+        if let Some(mut prev) = regions.next() {
+            for region in regions {
+                let gap_start = prev
+                    .0
+                    .add_usize(prev.1.len())
+                    .expect("there is a key after the region, so the key after the region exists");
+                let gap_len = region
+                    .0
+                    .difference(&gap_start)
+                    .expect("gap is too large to fill");
+                let gap_end = gap_start
+                    .add_usize(gap_len - 1)
+                    .expect("the gap ends before the key of the next region");
+                gaps.push((gap_start, gap_end, vec![fill.clone(); gap_len]));
+                prev = region;
+            }
+        }
+
+        let mut regions = core::mem::take(&mut self.map).into_iter();
+        let (start_key, mut vec) = match regions.next() {
+            Some(region) => region,
+            None => return Vec::new(),
+        };
+        let mut filled = Vec::with_capacity(gaps.len());
+        for ((gap_start, gap_end, gap), (_, values)) in gaps.into_iter().zip(regions) {
+            self.length += gap.len();
+            vec.extend(gap);
+            vec.extend(values);
+            filled.push((gap_start, gap_end));
+        }
+        self.map.insert(start_key, vec);
+        self.debug_check_length();
+        filled
+    }
+
+    /// Inserts a value for every key within a range that does not already have a value.
+    /// The value for each key is made by calling `f` with the key.
+    /// Existing values are left unchanged.
+    ///
+    /// Afterwards all keys within the range are in a single contiguous region.
+    /// An unbounded start or end of the range is treated as the
+    /// first or last key in this map respectively.
+    pub fn fill_gaps_with<R: RangeBounds<K>, F: FnMut(K) -> V>(&mut self, range: R, mut f: F) {
+        let mut key = match range.start_bound() {
+            Bound::Included(start) => start.clone(),
+            Bound::Excluded(start) => match start.add_one() {
+                Some(key) => key,
+                None => return,
+            },
+            Bound::Unbounded => match self.first() {
+                Some(first) => first.key,
+                None => return,
+            },
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => Bound::Included(end.clone()),
+            Bound::Excluded(end) => Bound::Excluded(end.clone()),
+            Bound::Unbounded => match self.last() {
+                Some(last) => Bound::Included(
+                    last.key
+                        .add_usize(last.offset)
+                        .expect("all values in the map have a valid key"),
+                ),
+                None => return,
+            },
+        };
+        loop {
+            let in_range = match &end {
+                Bound::Included(end) => key <= *end,
+                Bound::Excluded(end) => key < *end,
+                Bound::Unbounded => unreachable!("unbounded end replaced with the last key"),
+            };
+            if !in_range {
+                return;
+            }
+            let next_key = match self.find(&key) {
+                Some(index) => {
+                    // skip over the rest of the region
+                    let len = self.map[&index.key].len();
+                    index.key.add_usize(len)
+                }
+                None => {
+                    self.insert(key.clone(), f(key.clone()));
+                    key.add_one()
+                }
+            };
+            key = match next_key {
+                Some(key) => key,
+                None => return,
+            };
         }
     }
 
@@ -250,8 +1110,11 @@ impl<K: Key, V> ContiguousMap<K, V> {
                     self.map
                         .remove(key)
                         .expect("removing now empty entry from map that we know exists");
+                } else {
+                    self.shrink_policy.apply(entry.1);
                 }
                 self.length -= 1;
+                self.debug_check_length();
                 Some(value)
             }
             Ordering::Less => {
@@ -266,8 +1129,10 @@ impl<K: Key, V> ContiguousMap<K, V> {
                         "key has a value for the next adjacent key, this next key must exist",
                     );
                     let value = entry.1.remove(0);
+                    self.shrink_policy.apply(&mut entry.1);
                     self.map.insert(entry.0, entry.1);
                     self.length -= 1;
+                    self.debug_check_length();
                     Some(value)
                 } else {
                     // split off the tail of the vector, creating a new entry for it
@@ -275,18 +1140,259 @@ impl<K: Key, V> ContiguousMap<K, V> {
                     let value = entry.1.pop().expect(
                         "removing last item from vector whose size is known to be at least 2",
                     );
+                    self.shrink_policy.apply(entry.1);
                     let tail_key = key.add_one().expect(
                         "key has a value for the next adjacent key, this next key must exist",
                     );
                     self.map.insert(tail_key, tail);
                     self.length -= 1;
+                    self.debug_check_length();
                     Some(value)
                 }
             }
         }
     }
 
-    /// Removes all entries from this map.
+    /// Removes the first value of the contiguous region containing a key.
+    /// Returns the removed value and its key, or None if the key is not in this map.
+    ///
+    /// The start key of the region is shifted up by one,
+    /// and the region is removed entirely if it becomes empty.
+    pub fn region_pop_front<KB: Borrow<K>>(&mut self, key: KB) -> Option<(K, V)> {
+        let index = self.find(key.borrow())?;
+        let mut vec = self
+            .map
+            .remove(&index.key)
+            .expect("removing entry that is known to exist");
+        let value = vec.remove(0);
+        if !vec.is_empty() {
+            let new_start = index
+                .key
+                .add_one()
+                .expect("region has a value for the next adjacent key, this next key must exist");
+            self.map.insert(new_start, vec);
+        }
+        self.length -= 1;
+        self.debug_check_length();
+        Some((index.key, value))
+    }
+
+    /// Removes the last value of the contiguous region containing a key.
+    /// Returns the removed value and its key, or None if the key is not in this map.
+    ///
+    /// The region is removed entirely if it becomes empty.
+    pub fn region_pop_back<KB: Borrow<K>>(&mut self, key: KB) -> Option<(K, V)> {
+        let index = self.find(key.borrow())?;
+        let vec = self
+            .map
+            .get_mut(&index.key)
+            .expect("lookup with key of an entry that is known to exist");
+        let value = vec
+            .pop()
+            .expect("internal vectors in ContiguousMap are never empty");
+        let value_key = index
+            .key
+            .add_usize(vec.len())
+            .expect("all values in the map have a valid key");
+        if vec.is_empty() {
+            self.map.remove(&index.key);
+        }
+        self.length -= 1;
+        self.debug_check_length();
+        Some((value_key, value))
+    }
+
+    /// Removes the entire contiguous region that contains a key.
+    /// Returns None and leaves this map unchanged if the key is not in this map.
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a vector of all of the values in the region.
+    pub fn remove_region<KB: Borrow<K>>(&mut self, key: KB) -> Option<(K, Vec<V>)> {
+        let index = self.find(key.borrow())?;
+        let region = self
+            .map
+            .remove_entry(&index.key)
+            .expect("index was found in this map");
+        self.length -= region.1.len();
+        self.debug_check_length();
+        Some(region)
+    }
+
+    /// Rebuilds the internal storage of this map from its current contiguous regions.
+    ///
+    /// This does not change the contents of this map.
+    /// It may be used to compact long lived maps that have had many regions removed.
+    pub fn rebuild(&mut self) {
+        self.map = core::mem::take(&mut self.map).into_iter().collect();
+    }
+
+    /// Merges every pair of adjacent contiguous regions in this map.
+    ///
+    /// A ContiguousMap always keeps adjacent regions merged,
+    /// so this is a no-op unless the regions have been split by lower level edits.
+    /// Equivalent to `map.coalesce_range(..)`.
+    pub fn coalesce(&mut self) {
+        self.coalesce_range(..);
+    }
+
+    /// Merges any adjacent contiguous regions that start within a range of keys
+    /// into the region before them.
+    ///
+    /// A ContiguousMap always keeps adjacent regions merged,
+    /// so this is a no-op unless the regions have been split by lower level edits.
+    /// Only the regions starting within the range are inspected.
+    pub fn coalesce_range<R: RangeBounds<K>>(&mut self, range: R) {
+        let in_range = |key: &&K| match range.end_bound() {
+            Bound::Included(end) => *key <= end,
+            Bound::Excluded(end) => *key < end,
+            Bound::Unbounded => true,
+        };
+        let start_keys: Vec<K> = self
+            .map
+            .range((range.start_bound(), Bound::Unbounded))
+            .map(|(key, _)| key)
+            .take_while(in_range)
+            .cloned()
+            .collect();
+        for key in start_keys {
+            let prev = match self.map.range_mut(..&key).next_back() {
+                Some(prev) => prev,
+                None => continue,
+            };
+            if prev.0.add_usize(prev.1.len()).as_ref() == Some(&key) {
+                let values = self
+                    .map
+                    .remove(&key)
+                    .expect("key was collected from the map");
+                self.map
+                    .range_mut(..&key)
+                    .next_back()
+                    .expect("previous region is still in the map")
+                    .1
+                    .extend(values);
+            }
+        }
+    }
+
+    /// Adds `delta` to the key of every value in this map.
+    ///
+    /// Returns [`ShiftError`] and leaves this map unchanged
+    /// if any key would overflow the key type.
+    pub fn shift_keys(&mut self, delta: usize) -> Result<(), ShiftError> {
+        // keys keep their order, so only the largest key can overflow
+        if let Some(last) = self.last() {
+            last.key
+                .add_usize(last.offset)
+                .and_then(|key| key.add_usize(delta))
+                .ok_or(ShiftError)?;
+        }
+        self.map = core::mem::take(&mut self.map)
+            .into_iter()
+            .map(|(key, vec)| {
+                let key = key
+                    .add_usize(delta)
+                    .expect("the largest key did not overflow");
+                (key, vec)
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Splits this map in two at a key.
+    /// Returns a new map containing all entries with a key at or above the given key,
+    /// leaving all entries with a key below the given key in this map.
+    ///
+    /// A contiguous region that contains the given key is split in two.
+    pub fn split_off<KB: Borrow<K>>(&mut self, key: KB) -> Self {
+        let key = key.borrow();
+        let mut tail_map = self.map.split_off(key);
+        // the last region remaining in this map may extend past the key
+        if let Some((start_key, vec)) = self.map.iter_mut().next_back() {
+            if let Some(offset) = key.difference(start_key) {
+                if offset < vec.len() {
+                    tail_map.insert(key.clone(), vec.split_off(offset));
+                }
+            }
+        }
+        let tail_length = tail_map.values().map(Vec::len).sum();
+        self.length -= tail_length;
+        self.debug_check_length();
+        Self {
+            map: tail_map,
+            length: tail_length,
+            shrink_policy: self.shrink_policy,
+        }
+    }
+
+    /// Retains only the entries for which a predicate returns true.
+    /// Contiguous regions are split where entries are removed.
+    pub fn retain<F: FnMut(K, &mut V) -> bool>(&mut self, f: F) {
+        self.retain_count(f);
+    }
+
+    /// Retains only the entries for which a predicate returns true.
+    /// Returns the number of entries that were removed.
+    /// If the predicate panics, every entry it has not rejected is left in this map.
+    ///
+    /// See [`ContiguousMap::retain()`].
+    pub fn retain_count<F: FnMut(K, &mut V) -> bool>(&mut self, mut f: F) -> usize {
+        let old_length = self.length;
+        let mut next_region_key = self.map.keys().next().cloned();
+        while let Some((start_key, len)) = next_region_key
+            .as_ref()
+            .and_then(|key| self.map.range(key..).next())
+            .map(|(start_key, vec)| (start_key.clone(), vec.len()))
+        {
+            // the kept runs are reinserted behind the next region key
+            next_region_key = start_key.add_usize(len);
+            RegionFilter::new(self, start_key).filter(&mut f, |_, _| {});
+        }
+        old_length - self.length
+    }
+
+    /// Retains only the contiguous regions for which a predicate returns true.
+    ///
+    /// The predicate is given the start key of each region and may edit its values.
+    /// Regions left empty are removed.
+    /// The start key of a region is fixed, so values may only be removed
+    /// from the back of a region, not from the front.
+    ///
+    /// # Panics
+    /// Panics if the predicate extends a region so that it overlaps the next region
+    /// or overflows the key type.
+    /// If this function panics, or the predicate panics, the region being examined is removed
+    /// and every other region is left in this map.
+    pub fn retain_regions<F: FnMut(K, &mut Vec<V>) -> bool>(&mut self, mut f: F) {
+        let mut guard = RetainRegionsGuard {
+            map: self,
+            retained: BTreeMap::new(),
+        };
+        while let Some((start_key, mut vec)) = guard.map.map.pop_first() {
+            if !f(start_key.clone(), &mut vec) || vec.is_empty() {
+                continue;
+            }
+            guard.map.shrink_policy.apply(&mut vec);
+            let last_key = start_key
+                .add_usize(vec.len() - 1)
+                .expect("retain_regions predicate overflowed the key type");
+            if let Some(next_key) = guard.map.map.keys().next() {
+                assert!(
+                    last_key < *next_key,
+                    "retain_regions predicate extended a region into the next region"
+                );
+            }
+            if let Some((prev_key, prev_vec)) = guard.retained.iter_mut().next_back() {
+                if prev_key.add_usize(prev_vec.len()).as_ref() == Some(&start_key) {
+                    // the previous region was extended to be adjacent
+                    prev_vec.extend(vec);
+                    continue;
+                }
+            }
+            guard.retained.insert(start_key, vec);
+        }
+    }
+
+    /// Removes all entries from this map.
     pub fn clear(&mut self) {
         self.map.clear();
         self.length = 0;
@@ -294,10 +1400,51 @@ impl<K: Key, V> ContiguousMap<K, V> {
 
     /// Removes all entries within a range of keys.
     pub fn clear_range<R: RangeBounds<K>>(&mut self, range: R) {
+        self.take_range(range);
+    }
+
+    /// Removes all entries within a range of keys.
+    /// Returns the removed entries in ascending key order.
+    pub fn remove_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, V)> {
+        self.drain_range(range).collect()
+    }
+
+    /// Removes all entries within a range of keys.
+    /// Returns an iterator over the removed entries in ascending key order.
+    ///
+    /// The range is removed from this map eagerly, before this function returns,
+    /// so dropping the iterator early still removes the entire range.
+    /// The removed regions are detached rather than copied,
+    /// and their values are only moved out as the iterator is advanced.
+    pub fn drain_range<R: RangeBounds<K>>(&mut self, range: R) -> DrainRange<K, V> {
+        DrainRange::new(self.take_range(range))
+    }
+
+    /// Removes every entry for which a predicate returns true.
+    /// Returns an iterator over the removed entries in ascending key order.
+    ///
+    /// The predicate may modify the values of entries that it does not remove.
+    /// Like [`ContiguousMap::retain()`] removing values from the middle of a
+    /// contiguous region splits it.
+    ///
+    /// Entries are removed one contiguous region at a time as the iterator is advanced,
+    /// taking O(n) time overall.
+    /// Dropping the iterator early leaves the entries of every region it has not reached
+    /// in this map.
+    pub fn extract_if<F: FnMut(K, &mut V) -> bool>(&mut self, pred: F) -> ExtractIf<'_, K, V, F> {
+        ExtractIf::new(self, pred)
+    }
+
+    /// Removes all entries within a range of keys.
+    /// Returns the removed values grouped into contiguous regions in ascending key order.
+    fn take_range<R: RangeBounds<K>>(&mut self, range: R) -> Vec<(K, Vec<V>)> {
         let (start, end) = match self.find_range(range) {
             Some(range) => range,
-            None => return,
+            None => return Vec::new(),
         };
+        let start_key = start.key.add_usize(start.offset).unwrap();
+        let after_end_key = end.key.add_usize(end.offset).unwrap().add_one();
+        let mut removed = Vec::new();
 
         if start.key == end.key {
             // entire removal is in a single region
@@ -305,39 +1452,30 @@ impl<K: Key, V> ContiguousMap<K, V> {
             match (start.offset == 0, end.offset == (vec.len() - 1)) {
                 (true, true) => {
                     // remove entire entry
-                    let removed = self.map.remove(&start.key).unwrap();
-                    self.length -= removed.len();
+                    removed.push((start_key, self.map.remove(&start.key).unwrap()));
                 }
                 (false, true) => {
-                    // pop off elements from the back of the vec
-                    self.length -= vec.len() - start.offset;
-                    vec.truncate(start.offset);
+                    // split off elements from the back of the vec
+                    removed.push((start_key, vec.split_off(start.offset)));
+                    self.shrink_policy.apply(vec);
                 }
                 (true, false) => {
                     // extract the vec
                     let mut vec = self.map.remove(&start.key).unwrap();
-                    // remove the front of the vector that was marked for clearing
-                    let num_to_remove = end.offset + 1;
-                    vec.rotate_left(num_to_remove);
-                    self.length -= num_to_remove;
-                    vec.truncate(vec.len() - num_to_remove);
+                    // split the tail that will be retained off of vec
+                    let tail = vec.split_off(end.offset + 1);
+                    removed.push((start_key, vec));
                     // add the tail back into the map right after the region of clearing
-                    self.map.insert(
-                        end.key.add_usize(end.offset).unwrap().add_one().unwrap(),
-                        vec,
-                    );
+                    self.map.insert(after_end_key.unwrap(), tail);
                 }
                 (false, false) => {
                     // split the tail that will be retained off of vec
                     let tail = vec.split_off(end.offset + 1);
                     // remove the interior elements marked for clearing
-                    self.length -= vec.len() - start.offset;
-                    vec.truncate(start.offset);
+                    removed.push((start_key, vec.split_off(start.offset)));
+                    self.shrink_policy.apply(vec);
                     // insert the tail back into the map right after the region of clearing
-                    self.map.insert(
-                        end.key.add_usize(end.offset).unwrap().add_one().unwrap(),
-                        tail,
-                    );
+                    self.map.insert(after_end_key.unwrap(), tail);
                 }
             }
         } else {
@@ -346,13 +1484,12 @@ impl<K: Key, V> ContiguousMap<K, V> {
             // handle the start region
             if start.offset == 0 {
                 // remove entire entry
-                let removed = self.map.remove(&start.key).unwrap();
-                self.length -= removed.len();
+                removed.push((start_key, self.map.remove(&start.key).unwrap()));
             } else {
                 // remove the tail of the entry
                 let vec = self.map.get_mut(&start.key).unwrap();
-                self.length -= vec.len() - start.offset;
-                vec.truncate(start.offset);
+                removed.push((start_key, vec.split_off(start.offset)));
+                self.shrink_policy.apply(vec);
             }
 
             // remove any regions between start and end
@@ -362,31 +1499,24 @@ impl<K: Key, V> ContiguousMap<K, V> {
                 .next()
             {
                 let key = key.clone();
-                let removed = self.map.remove(&key).unwrap();
-                self.length -= removed.len();
+                let vec = self.map.remove(&key).unwrap();
+                removed.push((key, vec));
             }
 
             // handle the end region
-            let vec = self.map.get(&end.key).unwrap();
-            if vec.len() - 1 == end.offset {
-                // remove entire region
-                let removed = self.map.remove(&end.key).unwrap();
-                self.length -= removed.len();
-            } else {
-                // extract the vec
-                let mut vec = self.map.remove(&end.key).unwrap();
-                // remove the front of the vector that was marked for clearing
-                let num_to_remove = end.offset + 1;
-                vec.rotate_left(num_to_remove);
-                self.length -= num_to_remove;
-                vec.truncate(vec.len() - num_to_remove);
+            let mut vec = self.map.remove(&end.key).unwrap();
+            if vec.len() - 1 != end.offset {
+                // split the tail that will be retained off of vec
+                let tail = vec.split_off(end.offset + 1);
                 // add the tail back into the map right after the region of clearing
-                self.map.insert(
-                    end.key.add_usize(end.offset).unwrap().add_one().unwrap(),
-                    vec,
-                );
+                self.map.insert(after_end_key.unwrap(), tail);
             }
+            removed.push((end.key, vec));
         }
+
+        self.length -= removed.iter().map(|(_, vec)| vec.len()).sum::<usize>();
+        self.debug_check_length();
+        removed
     }
 
     /// Removes all entries starting at the provided key for the next len adjacent keys.
@@ -399,6 +1529,111 @@ impl<K: Key, V> ContiguousMap<K, V> {
         }
     }
 
+    /// Returns true if this map contains a value for a key.
+    pub fn contains_key<KB: Borrow<K>>(&self, key: KB) -> bool {
+        self.find(key.borrow()).is_some()
+    }
+
+    /// Returns true if this map contains a value for every key in a range.
+    ///
+    /// This is equivalent to `map.get_slice(range).is_some()`,
+    /// so empty ranges are never contained.
+    pub fn contains_range<R: InclusiveStartRangeBounds<K>>(&self, range: R) -> bool {
+        self.contains_range_impl(&range).unwrap_or(false)
+    }
+
+    /// Implementation of [`ContiguousMap::contains_range()`]
+    /// that returns None when the range starts outside of every region.
+    fn contains_range_impl<R: InclusiveStartRangeBounds<K>>(&self, range: &R) -> Option<bool> {
+        let start = range.start_bound();
+        let (region_key, vec) = self.map.range(..=start).next_back()?;
+        let offset = start.difference(region_key)?;
+        let available = vec.len().checked_sub(offset)?;
+        let length = match range.end_bound() {
+            Bound::Unbounded => available,
+            Bound::Excluded(end) => end.difference(start)?,
+            Bound::Included(inclusive_end) => inclusive_end.difference(start)?.checked_add(1)?,
+        };
+        Some(length != 0 && length <= available)
+    }
+
+    /// Gets the starting key of the contiguous region that contains a key.
+    /// Returns None if the key is not in this map.
+    pub fn region_for_key<KB: Borrow<K>>(&self, key: KB) -> Option<K> {
+        self.find(key.borrow()).map(|index| index.key)
+    }
+
+    /// Gets the number of keys within a range that have values in this map.
+    ///
+    /// This sums the lengths of the contiguous regions within the range
+    /// instead of visiting every value.
+    pub fn count_in_range<R: RangeBounds<K>>(&self, range: R) -> usize {
+        let (start, end) = match self.find_range(range) {
+            Some(range) => range,
+            None => return 0,
+        };
+        if start.key == end.key {
+            return end.offset - start.offset + 1;
+        }
+        let start_len = self.map[&start.key].len() - start.offset;
+        let middle_len: usize = self
+            .map
+            .range((Bound::Excluded(&start.key), Bound::Excluded(&end.key)))
+            .map(|(_, vec)| vec.len())
+            .sum();
+        start_len + middle_len + end.offset + 1
+    }
+
+    /// Returns true if every key within a range has a value in this map.
+    /// This means the range lies entirely within a single contiguous region.
+    ///
+    /// An empty range is always contiguous.
+    /// An unbounded range is only contiguous if this map contains
+    /// the smallest or largest possible key.
+    pub fn is_range_contiguous<R: RangeBounds<K>>(&self, range: R) -> bool {
+        // find the inclusive bounds of the range, where None is unbounded
+        let end = match range.end_bound() {
+            Bound::Included(end) => Some(end.clone()),
+            Bound::Excluded(end) => match end.sub_one() {
+                Some(end) => Some(end),
+                None => return true,
+            },
+            Bound::Unbounded => None,
+        };
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(start.clone()),
+            Bound::Excluded(start) => match start.add_one() {
+                Some(start) => Some(start),
+                None => return true,
+            },
+            Bound::Unbounded => None,
+        };
+        if let (Some(start), Some(end)) = (&start, &end) {
+            if start > end {
+                return true;
+            }
+        }
+
+        // find the region that must contain the entire range
+        let (region_key, vec) = match start {
+            Some(start) => match self.find(&start) {
+                Some(index) => (index.key.clone(), &self.map[&index.key]),
+                None => return false,
+            },
+            None => match self.map.iter().next() {
+                Some((key, vec)) if key.sub_one().is_none() => (key.clone(), vec),
+                _ => return false,
+            },
+        };
+        let region_end = region_key
+            .add_usize(vec.len() - 1)
+            .expect("all values in the map have a valid key");
+        match end {
+            Some(end) => end <= region_end,
+            None => region_end.add_one().is_none(),
+        }
+    }
+
     /// Returns a reference to a key's value, if it exists.
     pub fn get<KB: Borrow<K>>(&self, key: KB) -> Option<&V> {
         let key = key.borrow();
@@ -407,6 +1642,19 @@ impl<K: Key, V> ContiguousMap<K, V> {
         entry.1.get(index)
     }
 
+    /// Returns a key and a reference to its value, if it exists.
+    ///
+    /// Unlike [`std::collections::BTreeMap`] the key is returned directly
+    /// instead of as a reference to a key.
+    /// This is due to how contiguous regions are stored internally.
+    pub fn get_key_value<KB: Borrow<K>>(&self, key: KB) -> Option<(K, &V)> {
+        let key = key.borrow();
+        let entry = self.map.range(..=key).next_back()?;
+        let offset = key.difference(entry.0)?;
+        let value = entry.1.get(offset)?;
+        Some((entry.0.add_usize(offset)?, value))
+    }
+
     /// Returns a mutable reference to a key's value, if it exists.
     pub fn get_mut<KB: Borrow<K>>(&mut self, key: KB) -> Option<&mut V> {
         let key = key.borrow();
@@ -415,6 +1663,150 @@ impl<K: Key, V> ContiguousMap<K, V> {
         entry.1.get_mut(index)
     }
 
+    /// Gets mutable references to the values of several keys at once.
+    ///
+    /// Returns None if any of the keys are not in this map
+    /// or if any two of the keys are equal.
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [K; N]) -> Option<[&mut V; N]> {
+        // (index into keys, index into this map) sorted by index into this map
+        let mut indices = Vec::with_capacity(N);
+        for (i, key) in keys.iter().enumerate() {
+            indices.push((i, self.find(key)?));
+        }
+        indices.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+        if indices.windows(2).any(|pair| pair[0].1 == pair[1].1) {
+            return None;
+        }
+
+        let mut values: [Option<&mut V>; N] = [(); N].map(|_| None);
+        if let (Some(first), Some(last)) = (indices.first(), indices.last()) {
+            let regions = self
+                .map
+                .range_mut(&first.1.key..=&last.1.key)
+                .filter(|(key, _)| indices.iter().any(|(_, index)| &index.key == *key));
+            let mut indices = indices.iter().peekable();
+            for (region_key, vec) in regions {
+                let mut rest = vec.as_mut_slice();
+                let mut consumed = 0;
+                while let Some((i, index)) = indices.next_if(|(_, index)| &index.key == region_key)
+                {
+                    let (value, tail) = core::mem::take(&mut rest)[index.offset - consumed..]
+                        .split_first_mut()
+                        .expect("offset is within the region");
+                    values[*i] = Some(value);
+                    rest = tail;
+                    consumed = index.offset + 1;
+                }
+            }
+        }
+        Some(values.map(|value| value.expect("every key was found in this map")))
+    }
+
+    /// Gets a vector of `len` optional values for the keys starting at `start`.
+    ///
+    /// Each value in the vector is `Some` clone of the value for the key
+    /// that is its index in the vector plus `start`, or `None` if there is
+    /// no value for that key.
+    /// This is the inverse of [`ContiguousMap::from_option_vec()`].
+    pub fn to_option_vec(&self, start: K, len: usize) -> Vec<Option<V>>
+    where
+        V: Clone,
+    {
+        let mut vec = vec![None; len];
+        let range = match start.add_usize(len) {
+            Some(end) => self.range(start.clone()..end),
+            None => self.range(start.clone()..),
+        };
+        for (key, value) in range {
+            let offset = key
+                .difference(&start)
+                .expect("key is in a range starting at start and of length len");
+            vec[offset] = Some(value.clone());
+        }
+        vec
+    }
+
+    /// Gets the entire contiguous region that contains a key.
+    /// Returns None if the key is not in this map.
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a slice of all of the values in the region.
+    pub fn get_contiguous_region<KB: Borrow<K>>(&self, key: KB) -> Option<(K, &[V])> {
+        let key = key.borrow();
+        let entry = self.map.range(..=key).next_back()?;
+        let offset = key.difference(entry.0)?;
+        if offset < entry.1.len() {
+            Some((entry.0.clone(), &entry.1[..]))
+        } else {
+            None
+        }
+    }
+
+    /// Gets the entire contiguous region that contains a key as a mutable slice.
+    /// Returns None if the key is not in this map.
+    ///
+    /// The returned tuple contains the key of the first value in the region
+    /// and a mutable slice of all of the values in the region.
+    pub fn get_contiguous_region_mut<KB: Borrow<K>>(&mut self, key: KB) -> Option<(K, &mut [V])> {
+        let key = key.borrow();
+        let entry = self.map.range_mut(..=key).next_back()?;
+        let offset = key.difference(entry.0)?;
+        if offset < entry.1.len() {
+            Some((entry.0.clone(), &mut entry.1[..]))
+        } else {
+            None
+        }
+    }
+
+    /// Gets the capacity of the vector backing the contiguous region that contains a key.
+    /// Returns None if the key is not in this map.
+    ///
+    /// The capacity is always at least the number of values in the region.
+    pub fn region_capacity<KB: Borrow<K>>(&self, key: KB) -> Option<usize> {
+        let key = key.borrow();
+        let entry = self.map.range(..=key).next_back()?;
+        let offset = key.difference(entry.0)?;
+        if offset < entry.1.len() {
+            Some(entry.1.capacity())
+        } else {
+            None
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more values in the
+    /// contiguous region that contains a key.
+    ///
+    /// If the key is not in this map but is one after the end of a region,
+    /// capacity is reserved in that region since inserting the key would append to it.
+    /// Otherwise this does nothing, as a region can not be empty.
+    ///
+    /// This never changes the values in this map.
+    pub fn reserve_region(&mut self, key: K, additional: usize) {
+        if let Some((region_key, vec)) = self.map.range_mut(..=&key).next_back() {
+            match key.difference(region_key) {
+                Some(offset) if offset <= vec.len() => vec.reserve(additional),
+                _ => {}
+            }
+        }
+    }
+
+    /// Gets the longest slice of values that starts at a key.
+    /// Returns None if the key is not in this map.
+    ///
+    /// The returned tuple contains the given key and a slice of the values
+    /// from that key to the end of its contiguous region.
+    pub fn longest_slice_from<KB: Borrow<K>>(&self, key: KB) -> Option<(K, &[V])> {
+        let key = key.borrow();
+        let entry = self.map.range(..=key).next_back()?;
+        let offset = key.difference(entry.0)?;
+        let slice = entry.1.get(offset..)?;
+        if slice.is_empty() {
+            None
+        } else {
+            Some((key.clone(), slice))
+        }
+    }
+
     /// Gets a slice from this map using a range of keys.
     pub fn get_slice<R: InclusiveStartRangeBounds<K>>(&self, range: R) -> Option<&[V]> {
         let entry = self.map.range(..=range.start_bound()).next_back()?;
@@ -444,8 +1836,37 @@ impl<K: Key, V> ContiguousMap<K, V> {
             return None;
         }
         self.get_slice(key.borrow()..)
-            .map(|slice| slice.chunks_exact(len).next())
-            .flatten()
+            .and_then(|slice| slice.chunks_exact(len).next())
+    }
+
+    /// Gets a slice from this map for each `(start_key, len)` request in an iterator.
+    /// Returns the results in the same order as the requests.
+    ///
+    /// Each result is the same as calling [`ContiguousMap::get_slice_with_len()`]
+    /// with that request.
+    pub fn get_slices<I: IntoIterator<Item = (K, usize)>>(&self, requests: I) -> Vec<Option<&[V]>> {
+        requests
+            .into_iter()
+            .map(|(start_key, len)| self.get_slice_with_len(start_key, len))
+            .collect()
+    }
+
+    /// Gets a slice from this map using the key of its last value and a length.
+    ///
+    /// Returns None if `len` is zero or if any of the `len` keys ending at `end_key`
+    /// are not in this map, including when they would underflow the key type.
+    pub fn get_slice_ending_at<KB: Borrow<K>>(&self, end_key: KB, len: usize) -> Option<&[V]> {
+        if len == 0 {
+            return None;
+        }
+        let end_key = end_key.borrow();
+        let (region_key, vec) = self.map.range(..=end_key).next_back()?;
+        let end_offset = end_key.difference(region_key)?;
+        if end_offset >= vec.len() {
+            return None;
+        }
+        let start_offset = (end_offset + 1).checked_sub(len)?;
+        Some(&vec[start_offset..=end_offset])
     }
 
     /// Gets a mutable slice from this map using a range of keys.
@@ -481,8 +1902,59 @@ impl<K: Key, V> ContiguousMap<K, V> {
             return None;
         }
         self.get_slice_mut(key.borrow()..)
-            .map(|slice| slice.chunks_exact_mut(len).next())
-            .flatten()
+            .and_then(|slice| slice.chunks_exact_mut(len).next())
+    }
+
+    /// Gets the mutable slices that cover a range of keys in ascending key order.
+    /// Each slice is paired with the key of its first value.
+    /// Returns None if any key in the range is not in this map.
+    ///
+    /// The range is split into one slice per contiguous region it overlaps.
+    /// Adjacent contiguous regions are always merged,
+    /// so a range without a gap currently results in exactly one slice,
+    /// but callers should not depend on that layout.
+    /// A range without an end covers the values up to the next gap.
+    pub fn get_slices_mut<R: InclusiveStartRangeBounds<K>>(
+        &mut self,
+        range: R,
+    ) -> Option<Vec<(K, &mut [V])>> {
+        let mut remaining = match range.end_bound() {
+            Bound::Unbounded => None,
+            Bound::Excluded(end) => Some(end.difference(range.start_bound())?),
+            Bound::Included(inclusive_end) => Some(
+                inclusive_end
+                    .difference(range.start_bound())?
+                    .checked_add(1)?,
+            ),
+        };
+        if remaining == Some(0) {
+            return None;
+        }
+        let mut key = range.start_bound().clone();
+        let first_region_key = self.map.range(..=&key).next_back()?.0.clone();
+        let mut slices = Vec::new();
+        for (region_key, vec) in self.map.range_mut(first_region_key..) {
+            let offset = match key.difference(region_key) {
+                Some(offset) if offset < vec.len() => offset,
+                // a gap before this region
+                _ => break,
+            };
+            let slice = &mut vec[offset..];
+            let len = remaining.map_or(slice.len(), |remaining| remaining.min(slice.len()));
+            let (slice, _) = slice.split_at_mut(len);
+            let next_key = key.add_usize(len);
+            slices.push((key, slice));
+            remaining = remaining.map(|remaining| remaining - len);
+            if remaining == Some(0) {
+                return Some(slices);
+            }
+            key = next_key?;
+        }
+        if remaining.is_none() && !slices.is_empty() {
+            Some(slices)
+        } else {
+            None
+        }
     }
 
     /// Iteration over all keys and values in this map in ascending key order.
@@ -490,7 +1962,7 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// Unlike [`std::collections::BTreeMap`] the tuples yielded by the iterator
     /// contains a key directly instead of a reference to a key.
     /// This is due to how contiguous regions are stored internally.
-    pub fn iter(&self) -> Iter<K, V> {
+    pub fn iter(&self) -> Iter<'_, K, V> {
         self.into_iter()
     }
 
@@ -499,16 +1971,46 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// Unlike [`std::collections::BTreeMap`] the tuples yielded by the iterator
     /// contains a key directly instead of a reference to a key.
     /// This is due to how contiguous regions are stored internally.
-    pub fn iter_mut(&mut self) -> IterMut<K, V> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         self.into_iter()
     }
 
+    /// Calls a function on a mutable reference to every value in this map in ascending key order.
+    ///
+    /// Unlike [`ContiguousMap::iter_mut()`] no keys are computed,
+    /// the function is called on each region's values directly.
+    /// Prefer this over `iter_mut().for_each()` when the keys are not needed.
+    pub fn for_each_value_mut<F: FnMut(&mut V)>(&mut self, mut f: F) {
+        for vec in self.map.values_mut() {
+            vec.iter_mut().for_each(&mut f);
+        }
+    }
+
+    /// Iteration over all keys in this map in ascending order.
+    ///
+    /// Unlike [`std::collections::BTreeMap`] the iterator yields keys
+    /// directly instead of references to keys.
+    /// This is due to how contiguous regions are stored internally.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self)
+    }
+
+    /// Iteration over all values in this map in ascending key order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self)
+    }
+
+    /// Mutable iteration over all values in this map in ascending key order.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self)
+    }
+
     /// Iteration over a range of keys and values in this map in ascending key order.
     ///
     /// Unlike [`std::collections::BTreeMap`] the tuples yielded by the iterator
     /// contains a key directly instead of a reference to a key.
     /// This is due to how contiguous regions are stored internally.
-    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<K, V> {
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V> {
         match self.find_range(range) {
             None => Range::new_empty(),
             Some((start, end)) => Range::new(self, start, end),
@@ -520,7 +2022,7 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// Unlike [`std::collections::BTreeMap`] the tuples yielded by the iterator
     /// contains a key directly instead of a reference to a key.
     /// This is due to how contiguous regions are stored internally.
-    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> RangeMut<K, V> {
+    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> RangeMut<'_, K, V> {
         match self.find_range(range) {
             None => RangeMut::new_empty(),
             Some((start, end)) => RangeMut::new(self, start, end),
@@ -539,6 +2041,17 @@ impl<K: Key, V> ContiguousMap<K, V> {
         IterVec::new(self)
     }
 
+    /// Iteration over references to the vectors backing each contiguous region
+    /// in this map in ascending key order.
+    ///
+    /// Unlike [`ContiguousMap::iter_vec()`] this does not consume the map.
+    /// This exposes the capacity of each region as well as its values.
+    ///
+    /// The iterator will never yield a tuple with an empty vector.
+    pub fn iter_vec_ref(&self) -> impl Iterator<Item = (&K, &Vec<V>)> + '_ {
+        self.map.iter()
+    }
+
     /// Iteration over all keys and values in this map grouped up
     /// in contiguous regions in ascending key order.
     ///
@@ -547,10 +2060,28 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// the key in the tuple.
     ///
     /// The iterator will never yield a tuple with an empty slice.
-    pub fn iter_slice(&self) -> IterSlice<K, V> {
+    pub fn iter_slice(&self) -> IterSlice<'_, K, V> {
         IterSlice::new(self)
     }
 
+    /// Gets a view of the contiguous regions in this map
+    /// that can be iterated over in a `for` loop.
+    /// ```
+    /// use contiguous_map::cmap;
+    ///
+    /// let map = cmap!(1 => 11, 12; 5 => 15);
+    /// let mut lengths = Vec::new();
+    /// for (_key, slice) in map.slices() {
+    ///     lengths.push(slice.len());
+    /// }
+    /// assert_eq!(vec![2, 1], lengths);
+    /// ```
+    ///
+    /// Iterating yields the same tuples as [`ContiguousMap::iter_slice()`].
+    pub fn slices(&self) -> Slices<'_, K, V> {
+        Slices::new(self)
+    }
+
     /// Mutable iteration over all keys and values in this map grouped up
     /// in contiguous regions in ascending key order.
     ///
@@ -559,9 +2090,219 @@ impl<K: Key, V> ContiguousMap<K, V> {
     /// the key in the tuple.
     ///
     /// The iterator will never yield a tuple with an empty slice.
-    pub fn iter_slice_mut(&mut self) -> IterSliceMut<K, V> {
+    pub fn iter_slice_mut(&mut self) -> IterSliceMut<'_, K, V> {
         IterSliceMut::new(self)
     }
+
+    /// Iteration over all contiguous regions in this map in ascending key order.
+    ///
+    /// Equivalent to [`ContiguousMap::iter_slice()`] except that the start key
+    /// of each region is yielded by value instead of by reference,
+    /// matching the owned keys yielded by [`ContiguousMap::iter()`].
+    ///
+    /// The iterator will never yield a tuple with an empty slice.
+    pub fn regions(&self) -> impl Iterator<Item = (K, &[V])> + '_ {
+        self.iter_slice().map(|(key, slice)| (key.clone(), slice))
+    }
+
+    /// Iterates over non-overlapping chunks of `size` consecutive values
+    /// within each contiguous region in ascending key order.
+    ///
+    /// Each chunk is yielded with the key of its first value.
+    /// Chunks never cross a gap between contiguous regions,
+    /// so the last chunk of each region may be shorter than `size`.
+    /// ```
+    /// use contiguous_map::cmap;
+    ///
+    /// let map = cmap!(0 => 1, 2, 3; 5 => 6);
+    /// let chunks: Vec<_> = map.region_chunks(2).collect();
+    /// assert_eq!(vec![(0, &[1, 2][..]), (2, &[3][..]), (5, &[6][..])], chunks);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    pub fn region_chunks(&self, size: usize) -> impl Iterator<Item = (K, &[V])> + '_ {
+        assert!(size != 0, "chunk size must be non-zero");
+        self.map.iter().flat_map(move |(start_key, vec)| {
+            vec.chunks(size).enumerate().map(move |(i, chunk)| {
+                let key = start_key
+                    .add_usize(i * size)
+                    .expect("all values in the map have a valid key");
+                (key, chunk)
+            })
+        })
+    }
+
+    /// Iterates over every overlapping window of `size` consecutive values
+    /// within each contiguous region in ascending key order.
+    ///
+    /// Each window is yielded with the key of its first value.
+    /// Windows never cross a gap between contiguous regions,
+    /// so regions shorter than `size` yield no windows.
+    /// ```
+    /// use contiguous_map::cmap;
+    ///
+    /// let map = cmap!(0 => 1, 2, 3; 5 => 6);
+    /// let windows: Vec<_> = map.region_windows(2).collect();
+    /// assert_eq!(vec![(0, &[1, 2][..]), (1, &[2, 3][..])], windows);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    pub fn region_windows(&self, size: usize) -> impl Iterator<Item = (K, &[V])> + '_ {
+        assert!(size != 0, "window size must be non-zero");
+        self.map.iter().flat_map(move |(start_key, vec)| {
+            vec.windows(size).enumerate().map(move |(i, window)| {
+                let key = start_key
+                    .add_usize(i)
+                    .expect("all values in the map have a valid key");
+                (key, window)
+            })
+        })
+    }
+}
+
+impl<K: BoundedKey, V> ContiguousMap<K, V> {
+    /// Iterates over slices of exactly `width` consecutive values in ascending key order
+    /// whose start keys are aligned to a multiple of `width` after [`BoundedKey::LOWEST`].
+    ///
+    /// Windows never cross a gap between contiguous regions.
+    /// Partial windows at the edges of a region are skipped.
+    /// ```
+    /// use contiguous_map::cmap;
+    ///
+    /// let map = cmap!(1u8 => 'a', 'b', 'c', 'd', 'e', 'f');
+    /// let windows: Vec<_> = map.aligned_windows(2).collect();
+    /// assert_eq!(vec![(2, &['b', 'c'][..]), (4, &['d', 'e'][..])], windows);
+    /// ```
+    ///
+    /// The alignment origin is [`BoundedKey::LOWEST`], not zero,
+    /// so for signed keys the windows may not start at multiples of `width`.
+    /// ```
+    /// use contiguous_map::cmap;
+    ///
+    /// let map = cmap!(0i64 => 1, 2, 3, 4, 5, 6);
+    /// let windows: Vec<_> = map.aligned_windows(3).collect();
+    /// assert_eq!(vec![(1, &[2, 3, 4][..])], windows);
+    /// ```
+    ///
+    /// A region whose start key is more than `usize::MAX` keys after
+    /// [`BoundedKey::LOWEST`] has no computable alignment and yields no windows.
+    ///
+    /// # Panics
+    /// Panics if `width` is zero.
+    pub fn aligned_windows(&self, width: usize) -> impl Iterator<Item = (K, &[V])> + '_ {
+        assert!(width != 0, "window width must be non-zero");
+        self.map.iter().flat_map(move |(start_key, vec)| {
+            let skip = match start_key.difference(&K::LOWEST) {
+                Some(offset) => ((width - offset % width) % width).min(vec.len()),
+                None => vec.len(),
+            };
+            vec[skip..]
+                .chunks_exact(width)
+                .enumerate()
+                .map(move |(i, window)| {
+                    let key = start_key
+                        .add_usize(skip + i * width)
+                        .expect("all values in the map have a valid key");
+                    (key, window)
+                })
+        })
+    }
+}
+
+impl<V> ContiguousMap<char, V> {
+    /// Gets the number of code points between the first and last keys in this map inclusive.
+    /// Returns None if this map is empty.
+    ///
+    /// The surrogate code points `'\u{D800}'..='\u{DFFF}'` are not chars,
+    /// so they are not counted.
+    pub fn code_point_span(&self) -> Option<usize> {
+        self.key_span()
+    }
+}
+
+impl<K: Key, KInner: Key, T> ContiguousMap<K, ContiguousMap<KInner, T>> {
+    /// Iteration over all values in a map of maps in ascending `(outer key, inner key)` order.
+    ///
+    /// This is useful for a map that represents a sparse 2D grid,
+    /// where the outer map stores rows and each inner map stores the columns of a row.
+    /// The iterator yields tuples of `((row key, column key), &value)`.
+    pub fn flatten_rows(&self) -> impl Iterator<Item = ((K, KInner), &T)> + '_ {
+        self.iter().flat_map(|(row, columns)| {
+            columns
+                .iter()
+                .map(move |(column, value)| ((row.clone(), column), value))
+        })
+    }
+}
+
+impl<K: Key, V: Clone> Clone for ContiguousMap<K, V> {
+    fn clone(&self) -> Self {
+        let map = self
+            .map
+            .iter()
+            .map(|(key, vec)| {
+                // preserve spare capacity so the clone grows like the original
+                let mut cloned = Vec::with_capacity(vec.capacity());
+                cloned.extend_from_slice(vec);
+                (key.clone(), cloned)
+            })
+            .collect();
+        Self {
+            map,
+            length: self.length,
+            shrink_policy: self.shrink_policy,
+        }
+    }
+}
+
+impl<K: Key, V: PartialEq> PartialEq for ContiguousMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        // the shrink policy does not affect the contents of a map
+        self.map == other.map
+    }
+}
+
+impl<K: Key, V: Eq> Eq for ContiguousMap<K, V> {}
+
+impl<K: Key, V: PartialEq> PartialEq<BTreeMap<K, V>> for ContiguousMap<K, V> {
+    fn eq(&self, other: &BTreeMap<K, V>) -> bool {
+        self.length == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((key, value), (other_key, other_value))| {
+                    key == *other_key && value == other_value
+                })
+    }
+}
+
+/// Formats this map as its contiguous regions,
+/// for example `{10: [0, 1, 2], 20: [5]}`.
+impl<K: Key + fmt::Debug, V: fmt::Debug> fmt::Debug for ContiguousMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter_slice()).finish()
+    }
+}
+
+impl<K: Key, V: PartialOrd> PartialOrd for ContiguousMap<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<K: Key, V: Ord> Ord for ContiguousMap<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<K: Key + Hash, V: Hash> Hash for ContiguousMap<K, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // regions are always merged, so equal maps have identical regions
+        self.map.hash(state);
+    }
 }
 
 impl<K: Key, V> Default for ContiguousMap<K, V> {
@@ -570,5 +2311,66 @@ impl<K: Key, V> Default for ContiguousMap<K, V> {
     }
 }
 
+/// Builds a map from regions of values with adjacent keys.
+///
+/// The regions do not need to be sorted, separated, or non-empty.
+/// Where regions overlap, values from later regions overwrite earlier ones.
+/// Values whose keys would overflow the key type are dropped.
+impl<K: Key, V, const N: usize> From<[(K, Vec<V>); N]> for ContiguousMap<K, V> {
+    fn from(regions: [(K, Vec<V>); N]) -> Self {
+        let mut map = Self::new();
+        for (start_key, vec) in IntoIterator::into_iter(regions) {
+            map.replace_region(start_key, vec);
+        }
+        map
+    }
+}
+
+/// Gets a reference to the value for a key.
+///
+/// # Panics
+/// Panics if the key is not in the map.
+impl<K: Key, V, KB: Borrow<K>> core::ops::Index<KB> for ContiguousMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: KB) -> &V {
+        self.get(key).expect("key not found in ContiguousMap")
+    }
+}
+
+/// Gets a mutable reference to the value for a key.
+///
+/// # Panics
+/// Panics if the key is not in the map.
+impl<K: Key, V, KB: Borrow<K>> core::ops::IndexMut<KB> for ContiguousMap<K, V> {
+    fn index_mut(&mut self, key: KB) -> &mut V {
+        self.get_mut(key).expect("key not found in ContiguousMap")
+    }
+}
+
+impl<K: Key, V> From<BTreeMap<K, V>> for ContiguousMap<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K: Key, V> From<ContiguousMap<K, V>> for BTreeMap<K, V> {
+    fn from(map: ContiguousMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K: Key, V> FromIterator<(K, V)> for ContiguousMap<K, V> {
+    /// Creates a map from an iterator of `(Key, Value)` pairs.
+    ///
+    /// Pairs are inserted in order using [`ContiguousMap::insert()`],
+    /// so if a key appears multiple times its last value is kept.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.insert_many(iter);
+        map
+    }
+}
+
 #[cfg(test)]
 mod test;
@@ -20,7 +20,7 @@ macro_rules! cmap {
         {
             let mut _map = $crate::ContiguousMap::new();
             $(
-                let mut _key = ::std::option::Option::Some($key);
+                let mut _key = ::core::option::Option::Some($key);
                 $(
                     let k = _key.unwrap();
                     _key = <_ as $crate::Key>::add_one(&k);
@@ -31,3 +31,142 @@ macro_rules! cmap {
         }
     };
 }
+
+/// Implements [`ToIndex`](crate::ToIndex) and [`TryFromIndex`](crate::TryFromIndex)
+/// for a newtype over an unsigned integer whose values are spaced a fixed step apart.
+///
+/// The index of a value is the number of steps it is from an origin,
+/// so values one step apart are adjacent keys in a [`ContiguousMap`](crate::ContiguousMap).
+/// The origin defaults to zero.
+///
+/// Every value of the type must be the origin plus a multiple of the step.
+/// Converting any other value to an index panics in debug builds.
+///
+/// ## Example
+/// ```
+/// use contiguous_map::{cmap, impl_step_key};
+///
+/// /// A time in milliseconds quantized to 100ms buckets.
+/// #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+/// struct Bucket(u64);
+///
+/// impl_step_key!(Bucket(u64), 100);
+///
+/// let map = cmap!(Bucket(200) => 'a', 'b', 'c');
+/// assert_eq!(Some(&'c'), map.get(Bucket(400)));
+/// assert_eq!(None, map.get(Bucket(500)));
+///
+/// /// A port number counted from 1024 in steps of 2.
+/// #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+/// struct EvenPort(u16);
+///
+/// impl_step_key!(EvenPort(u16), 2, 1024);
+///
+/// let map = cmap!(EvenPort(1024) => 'a', 'b');
+/// assert_eq!(Some(&'b'), map.get(EvenPort(1026)));
+/// ```
+#[macro_export]
+macro_rules! impl_step_key {
+    ($ty:ident($inner:ty), $step:expr $(,)?) => {
+        $crate::impl_step_key!($ty($inner), $step, 0);
+    };
+    ($ty:ident($inner:ty), $step:expr, $origin:expr $(,)?) => {
+        impl $crate::ToIndex for $ty {
+            type Index = $inner;
+
+            fn to_index(&self) -> Self::Index {
+                let offset: $inner = self.0 - $origin;
+                ::core::debug_assert_eq!(
+                    0,
+                    offset % $step,
+                    "step key is not a multiple of its step from its origin"
+                );
+                offset / $step
+            }
+        }
+
+        impl $crate::TryFromIndex for $ty {
+            fn try_from_index(index: Self::Index) -> ::core::option::Option<Self> {
+                let offset: $inner = index.checked_mul($step)?;
+                ::core::option::Option::Some($ty(offset.checked_add($origin)?))
+            }
+        }
+    };
+}
+
+/// Defines a newtype over an integer that is only valid within an inclusive range
+/// and implements [`ToIndex`](crate::ToIndex), [`TryFromIndex`](crate::TryFromIndex),
+/// and [`BoundedKey`](crate::BoundedKey) for it.
+///
+/// The generated type derives `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`,
+/// `PartialOrd`, `Ord`, and `Hash`.
+/// It is constructed with `new()`, which returns None for values outside of the range,
+/// and its value is read with `get()`.
+/// Keys outside of the range are never produced by [`Key`](crate::Key) operations.
+///
+/// ## Example
+/// ```
+/// use contiguous_map::{bounded_key, BoundedKey, ContiguousMap};
+///
+/// bounded_key!(pub Port, u16, 1024..=65535);
+///
+/// assert_eq!(None, Port::new(80));
+/// assert_eq!(1024, Port::LOWEST.get());
+///
+/// let mut map = ContiguousMap::new();
+/// map.insert(Port::new(8080).unwrap(), "http");
+/// map.insert(Port::new(8081).unwrap(), "http-alt");
+/// assert_eq!(1, map.num_contiguous_regions());
+/// assert_eq!(Some(&"http-alt"), map.get(Port::new(8081).unwrap()));
+///
+/// let mut map = ContiguousMap::new();
+/// map.insert_slice(Port::LOWEST, &[1, 2]);
+/// assert_eq!(Some(&1), map.get(Port::new(1024).unwrap()));
+/// assert_eq!(None, Port::new(1023).and_then(|port| map.get(port)));
+/// ```
+#[macro_export]
+macro_rules! bounded_key {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $inner:ty, $min:literal ..= $max:literal $(,)?) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name($inner);
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Makes a new key.
+            /// Returns None if the value is outside of the valid range.
+            #[allow(unused_comparisons)]
+            $vis const fn new(value: $inner) -> ::core::option::Option<Self> {
+                if value >= $min && value <= $max {
+                    ::core::option::Option::Some(Self(value))
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            /// Gets the value of this key.
+            $vis const fn get(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl $crate::ToIndex for $name {
+            type Index = $inner;
+
+            fn to_index(&self) -> Self::Index {
+                self.0
+            }
+        }
+
+        impl $crate::TryFromIndex for $name {
+            fn try_from_index(index: Self::Index) -> ::core::option::Option<Self> {
+                Self::new(index)
+            }
+        }
+
+        impl $crate::BoundedKey for $name {
+            const LOWEST: Self = Self($min);
+            const HIGHEST: Self = Self($max);
+        }
+    };
+}
@@ -1,21 +1,21 @@
 use crate::Key;
-use std::ops::Bound;
+use core::ops::Bound;
 
-/// Trait similar to [`std::ops::RangeBounds`] that requires an inclusive start to the range.
+/// Trait similar to [`core::ops::RangeBounds`] that requires an inclusive start to the range.
 ///
 /// This means this trait is only usable for ranges like
-/// [`1..`](std::ops::RangeFrom),
-/// [`1..5`](std::ops::Range),
-/// and [`1..=5`](std::ops::RangeInclusive).
+/// [`1..`](core::ops::RangeFrom),
+/// [`1..5`](core::ops::Range),
+/// and [`1..=5`](core::ops::RangeInclusive).
 ///
 /// Ranges like
-/// [`..`](std::ops::RangeFull),
-/// [`..5`](std::ops::RangeTo), and
-/// [`..=5`](std::ops::RangeToInclusive) are not supported.
+/// [`..`](core::ops::RangeFull),
+/// [`..5`](core::ops::RangeTo), and
+/// [`..=5`](core::ops::RangeToInclusive) are not supported.
 ///
 /// This trait is used for looking up slices in a [`ContiguousMap`](crate::ContiguousMap).
 /// The semantics of the non-supported ranges are odd and not yet (and maybe never) implemented.
-/// For example, with [`..`](std::ops::RangeFull) you'd expect a slice that contains all of the
+/// For example, with [`..`](core::ops::RangeFull) you'd expect a slice that contains all of the
 /// values in the map, however only elements with adjacent keys can be in the same slice.
 pub trait InclusiveStartRangeBounds<K: Key> {
     /// The inclusive starting bound of this range.
@@ -25,7 +25,7 @@ pub trait InclusiveStartRangeBounds<K: Key> {
     fn end_bound(&self) -> Bound<&K>;
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::Range<K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::Range<K> {
     fn start_bound(&self) -> &K {
         &self.start
     }
@@ -35,7 +35,7 @@ impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::Range<K> {
     }
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::Range<&K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::Range<&K> {
     fn start_bound(&self) -> &K {
         self.start
     }
@@ -45,7 +45,7 @@ impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::Range<&K> {
     }
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeFrom<K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::RangeFrom<K> {
     fn start_bound(&self) -> &K {
         &self.start
     }
@@ -55,7 +55,7 @@ impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeFrom<K> {
     }
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeFrom<&K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::RangeFrom<&K> {
     fn start_bound(&self) -> &K {
         self.start
     }
@@ -65,7 +65,7 @@ impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeFrom<&K> {
     }
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeInclusive<K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::RangeInclusive<K> {
     fn start_bound(&self) -> &K {
         self.start()
     }
@@ -75,7 +75,7 @@ impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeInclusive<K> {
     }
 }
 
-impl<K: Key> InclusiveStartRangeBounds<K> for std::ops::RangeInclusive<&K> {
+impl<K: Key> InclusiveStartRangeBounds<K> for core::ops::RangeInclusive<&K> {
     fn start_bound(&self) -> &K {
         self.start()
     }
@@ -0,0 +1,93 @@
+use core::convert::TryInto;
+
+use crate::{ToIndex, TryFromIndex};
+
+/// A key into a segmented address space made of a segment selector and
+/// an offset within that segment.
+///
+/// Offsets within a segment are contiguous, and the last offset of a
+/// segment is adjacent to offset zero of the next segment.
+/// ```
+/// use contiguous_map::{ContiguousMap, SegKey};
+///
+/// let mut map = ContiguousMap::new();
+/// map.insert(SegKey::new(0, u32::MAX), 'a');
+/// map.insert(SegKey::new(1, 0), 'b');
+/// assert_eq!(1, map.num_contiguous_regions());
+/// ```
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub struct SegKey {
+    /// The segment selector.
+    pub seg: u8,
+    /// The offset within the segment.
+    pub off: u32,
+}
+
+impl SegKey {
+    /// Creates a new segmented key.
+    pub fn new(seg: u8, off: u32) -> Self {
+        Self { seg, off }
+    }
+}
+
+impl ToIndex for SegKey {
+    type Index = u64;
+
+    fn to_index(&self) -> Self::Index {
+        (self.seg as u64) << 32 | self.off as u64
+    }
+}
+
+impl TryFromIndex for SegKey {
+    fn try_from_index(index: Self::Index) -> Option<Self> {
+        let seg = (index >> 32).try_into().ok()?;
+        Some(Self::new(seg, index as u32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_util::assert_regions, ContiguousMap, Key};
+
+    #[test]
+    fn index_traits() {
+        let key = SegKey::new(3, 7);
+        assert_eq!(0x3_0000_0007, key.to_index());
+        assert_eq!(Some(key), SegKey::try_from_index(0x3_0000_0007));
+        assert_eq!(None, SegKey::try_from_index(0x100_0000_0000));
+    }
+
+    #[test]
+    fn add_one_crosses_segment() {
+        assert_eq!(Some(SegKey::new(1, 0)), SegKey::new(0, u32::MAX).add_one());
+        assert_eq!(None, SegKey::new(u8::MAX, u32::MAX).add_one());
+    }
+
+    #[test]
+    fn segments_coalesce() {
+        let mut map = ContiguousMap::new();
+        map.insert(SegKey::new(1, 1), 3);
+        map.insert(SegKey::new(0, u32::MAX), 1);
+        map.insert(SegKey::new(1, 0), 2);
+        assert_regions(&map, &[(SegKey::new(0, u32::MAX), vec![1, 2, 3])]);
+    }
+
+    #[test]
+    fn iter_crosses_segment() {
+        let mut map = ContiguousMap::new();
+        map.insert_slice(SegKey::new(0, u32::MAX - 1), &[1, 2, 3, 4]);
+        let keys: Vec<_> = map.iter().map(|(key, _)| key).collect();
+        assert_eq!(
+            vec![
+                SegKey::new(0, u32::MAX - 1),
+                SegKey::new(0, u32::MAX),
+                SegKey::new(1, 0),
+                SegKey::new(1, 1),
+            ],
+            keys
+        );
+        let rev_values: Vec<_> = map.iter().rev().map(|(_, value)| *value).collect();
+        assert_eq!(vec![4, 3, 2, 1], rev_values);
+    }
+}
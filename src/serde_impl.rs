@@ -0,0 +1,77 @@
+//! Implementations of [`Serialize`] and [`Deserialize`] for [`ContiguousMap`].
+//!
+//! A map is serialized as a sequence of `(Key, [Value])` contiguous regions
+//! in ascending key order, the same as what is yielded by [`ContiguousMap::iter_vec()`].
+
+use crate::{ContiguousMap, Key};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+impl<K: Key + Serialize, V: Serialize> Serialize for ContiguousMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.map.len()))?;
+        for region in self.map.iter() {
+            seq.serialize_element(&region)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, K: Key + Deserialize<'de>, V: Deserialize<'de>> Deserialize<'de> for ContiguousMap<K, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(RegionsVisitor(PhantomData))
+    }
+}
+
+/// Visitor that builds a [`ContiguousMap`] from a sequence of `(Key, Vec<Value>)` regions.
+///
+/// The regions must be non-empty, sorted by key, and non-overlapping.
+/// Adjacent regions are merged.
+struct RegionsVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K: Key + Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for RegionsVisitor<K, V> {
+    type Value = ContiguousMap<K, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence of (key, values) contiguous regions")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut map = ContiguousMap::new();
+        // the region most recently added to the map and the key of its last value
+        let mut prev: Option<(K, K)> = None;
+        while let Some((key, values)) = seq.next_element::<(K, Vec<V>)>()? {
+            if values.is_empty() {
+                return Err(A::Error::custom("contiguous region is empty"));
+            }
+            let last_key = key
+                .add_usize(values.len() - 1)
+                .ok_or_else(|| A::Error::custom("contiguous region overflows the key type"))?;
+            map.length += values.len();
+            if let Some((prev_start, prev_last)) = prev.take() {
+                if key <= prev_last {
+                    return Err(A::Error::custom(
+                        "contiguous regions are not sorted or are overlapping",
+                    ));
+                }
+                if prev_last.add_one().as_ref() == Some(&key) {
+                    // adjacent to the previous region, merge them
+                    map.map
+                        .get_mut(&prev_start)
+                        .expect("previous region was inserted into the map")
+                        .extend(values);
+                    prev = Some((prev_start, last_key));
+                    continue;
+                }
+            }
+            map.map.insert(key.clone(), values);
+            prev = Some((key, last_key));
+        }
+        Ok(map)
+    }
+}
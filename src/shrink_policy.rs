@@ -0,0 +1,30 @@
+use alloc::vec::Vec;
+
+/// Policy for when a [`ContiguousMap`](crate::ContiguousMap) reclaims the unused
+/// capacity of a contiguous region after values are removed from it.
+///
+/// See [`ContiguousMap::set_shrink_policy()`](crate::ContiguousMap::set_shrink_policy).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ShrinkPolicy {
+    /// Never reclaim capacity.
+    /// This is the default policy.
+    #[default]
+    Never,
+    /// Shrink a region's capacity to fit its length whenever
+    /// its length drops below this ratio of its capacity.
+    OnRatio(f32),
+}
+
+impl ShrinkPolicy {
+    /// Shrinks a region's vector if required by this policy.
+    pub(crate) fn apply<V>(self, vec: &mut Vec<V>) {
+        match self {
+            ShrinkPolicy::Never => {}
+            ShrinkPolicy::OnRatio(ratio) => {
+                if (vec.len() as f32) < ratio * (vec.capacity() as f32) {
+                    vec.shrink_to_fit();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    assert_eq!(0, map.aligned_windows(2).count());
+}
+
+#[test]
+fn aligned_region() {
+    let map = cmap!(0 => 10, 11, 12, 13);
+    let windows: Vec<_> = map.aligned_windows(2).collect();
+    assert_eq!(vec![(0, &[10, 11][..]), (2, &[12, 13][..])], windows);
+}
+
+#[test]
+fn unaligned_start() {
+    let map = cmap!(1 => 11, 12, 13, 14, 15);
+    let windows: Vec<_> = map.aligned_windows(2).collect();
+    assert_eq!(vec![(2, &[12, 13][..]), (4, &[14, 15][..])], windows);
+}
+
+#[test]
+fn partial_end() {
+    let map = cmap!(4 => 14, 15, 16, 17, 18);
+    let windows: Vec<_> = map.aligned_windows(4).collect();
+    assert_eq!(vec![(4, &[14, 15, 16, 17][..])], windows);
+}
+
+#[test]
+fn region_too_small() {
+    let map = cmap!(1 => 11, 12, 13);
+    assert_eq!(0, map.aligned_windows(4).count());
+}
+
+#[test]
+fn does_not_cross_gap() {
+    let map = cmap!(
+        0 => 10, 11, 12;
+        4 => 14, 15;
+    );
+    let windows: Vec<_> = map.aligned_windows(2).collect();
+    assert_eq!(vec![(0, &[10, 11][..]), (4, &[14, 15][..])], windows);
+}
+
+#[test]
+fn width_one() {
+    let map = cmap!(3 => 13, 14);
+    let windows: Vec<_> = map.aligned_windows(1).collect();
+    assert_eq!(vec![(3, &[13][..]), (4, &[14][..])], windows);
+}
+
+#[test]
+fn signed_keys() {
+    // alignment is relative to i8::MIN, which is a multiple of 4
+    let mut map = ContiguousMap::new();
+    map.insert_slice(-6i8, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let windows: Vec<_> = map.aligned_windows(4).collect();
+    assert_eq!(
+        vec![(-4, &[3, 4, 5, 6][..]), (0, &[7, 8, 9, 10][..])],
+        windows
+    );
+}
+
+#[test]
+fn signed_origin_is_lowest() {
+    let map = cmap!(0i64 => 1, 2, 3, 4, 5, 6);
+    let windows: Vec<_> = map.aligned_windows(3).collect();
+    assert_eq!(vec![(1, &[2, 3, 4][..])], windows);
+}
+
+#[test]
+fn large_keys() {
+    let map = cmap!(
+        0u128 => 1, 2;
+        u128::MAX - 3 => 1, 2, 3, 4;
+    );
+    let windows: Vec<_> = map.aligned_windows(2).collect();
+    assert_eq!(vec![(0, &[1, 2][..])], windows);
+}
+
+#[test]
+#[should_panic]
+fn zero_width() {
+    let map = cmap!(0 => 10);
+    let _ = map.aligned_windows(0);
+}
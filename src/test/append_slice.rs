@@ -0,0 +1,49 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty_map() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(None, map.append_slice(&[1, 2]));
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn empty_slice() {
+    let mut map = cmap!(1 => 11);
+    assert_eq!(Some(2), map.append_slice(&[]));
+    assert_map_same(&map, [(1, vec![11])]);
+}
+
+#[test]
+fn extends_last_region() {
+    let mut map = cmap!(
+        1 => 11;
+        5 => 15, 16;
+    );
+    assert_eq!(Some(7), map.append_slice(&[17, 18]));
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15, 16, 17, 18])]);
+}
+
+#[test]
+fn repeated() {
+    let mut map = cmap!(0 => 0);
+    let mut expected = vec![0];
+    for i in 0..10 {
+        let values = [i, i + 1, i + 2];
+        assert_eq!(Some(expected.len()), map.append_slice(&values));
+        expected.extend_from_slice(&values);
+    }
+    assert_map_same(&map, [(0, expected)]);
+}
+
+#[test]
+fn overflow() {
+    let mut map = ContiguousMap::new();
+    map.insert(u8::MAX - 2, 1);
+    assert_eq!(None, map.append_slice(&[2, 3, 4]));
+    assert_eq!(1, map.len());
+    assert_eq!(Some(u8::MAX - 1), map.append_slice(&[2, 3]));
+    assert_eq!(Some(&[1, 2, 3][..]), map.get_slice(u8::MAX - 2..));
+    assert_eq!(None, map.append_slice(&[]));
+}
@@ -0,0 +1,42 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+use std::collections::BTreeMap;
+
+#[test]
+fn from_empty() {
+    let map = ContiguousMap::from(BTreeMap::<usize, i32>::new());
+    assert_map_same(&map, []);
+    assert!(BTreeMap::from(map).is_empty());
+}
+
+#[test]
+fn from_btree_map() {
+    let btree_map: BTreeMap<usize, i32> = [(1, 11), (2, 12), (3, 13), (7, 17)]
+        .iter()
+        .cloned()
+        .collect();
+    let map = ContiguousMap::from(btree_map);
+    assert_map_same(&map, [(1, vec![11, 12, 13]), (7, vec![17])]);
+}
+
+#[test]
+fn into_btree_map() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    let btree_map = BTreeMap::from(map);
+    let entries: Vec<_> = btree_map.into_iter().collect();
+    assert_eq!(vec![(1, 11), (2, 12), (5, 15)], entries);
+}
+
+#[test]
+fn round_trip() {
+    let btree_map: BTreeMap<usize, i32> = [(3, 13), (1, 11), (2, 12), (10, 20), (11, 21)]
+        .iter()
+        .cloned()
+        .collect();
+    let map = ContiguousMap::from(btree_map.clone());
+    assert_eq!(2, map.num_contiguous_regions());
+    assert_eq!(btree_map, BTreeMap::from(map));
+}
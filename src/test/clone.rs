@@ -24,3 +24,31 @@ fn two_slices() {
     let cloned = map.clone();
     assert_map_same(&cloned, [(10, vec![1, 2, 3]), (20, vec![6, 7, 8, 9])]);
 }
+
+#[test]
+fn preserves_capacity_and_length() {
+    let mut map = cmap!(
+        10 => 1, 2, 3, 4, 5;
+        20 => 6;
+    );
+    map.region_pop_back(10);
+    map.region_pop_back(10);
+    let capacity = map.map[&10].capacity();
+    assert!(capacity > 3);
+
+    let cloned = map.clone();
+    assert!(cloned == map);
+    assert_eq!(4, cloned.len());
+    assert_eq!(capacity, cloned.map[&10].capacity());
+    assert_map_same(&cloned, [(10, vec![1, 2, 3]), (20, vec![6])]);
+}
+
+#[test]
+fn preserves_shrink_policy() {
+    let mut map = cmap!(1 => 1);
+    map.set_shrink_policy(crate::ShrinkPolicy::OnRatio(0.5));
+    assert_eq!(
+        crate::ShrinkPolicy::OnRatio(0.5),
+        map.clone().shrink_policy()
+    );
+}
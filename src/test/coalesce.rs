@@ -0,0 +1,34 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    map.coalesce();
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn canonical_unchanged() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    map.coalesce();
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn merges_split_regions() {
+    let mut map = ContiguousMap::from_raw_regions(vec![
+        (1, vec![11, 12]),
+        (3, vec![13]),
+        (4, vec![14, 15]),
+        (10, vec![20]),
+        (11, vec![21]),
+    ]);
+    assert_eq!(5, map.num_contiguous_regions());
+    map.coalesce();
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14, 15]), (10, vec![20, 21])]);
+    assert_eq!(7, map.len());
+}
@@ -0,0 +1,58 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    map.coalesce_range(..);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn canonical_unchanged() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    map.coalesce_range(..);
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn merges_in_range_only() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        10 => 20, 21;
+    );
+    // artificially split the regions
+    map.map.insert(3, vec![13]);
+    map.map.insert(4, vec![14]);
+    map.map.insert(12, vec![22]);
+    map.length += 3;
+
+    map.coalesce_range(2..=4);
+
+    let regions: Vec<_> = map.iter_slice().collect();
+    assert_eq!(
+        vec![
+            (&1, &[11, 12, 13, 14][..]),
+            (&10, &[20, 21][..]),
+            (&12, &[22][..])
+        ],
+        regions
+    );
+
+    map.coalesce_range(12..);
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14]), (10, vec![20, 21, 22])]);
+}
+
+#[test]
+fn excluded_end() {
+    let mut map = cmap!(1 => 11);
+    map.map.insert(2, vec![12]);
+    map.length += 1;
+    map.coalesce_range(..2);
+    assert_eq!(2, map.num_contiguous_regions());
+    map.coalesce_range(..3);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
@@ -0,0 +1,42 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<char, i32>::new();
+    assert_eq!(None, map.code_point_span());
+}
+
+#[test]
+fn single() {
+    let map = cmap!('a' => 1);
+    assert_eq!(Some(1), map.code_point_span());
+}
+
+#[test]
+fn with_gap() {
+    let map = cmap!(
+        'a' => 1, 2;
+        'z' => 3;
+    );
+    assert_eq!(Some(26), map.code_point_span());
+}
+
+#[test]
+fn across_surrogates() {
+    let map = cmap!(
+        '\u{D7FF}' => 1;
+        '\u{E000}' => 2;
+    );
+    // the keys are adjacent as the surrogates are skipped
+    assert_eq!(1, map.num_contiguous_regions());
+    assert_eq!(Some(2), map.code_point_span());
+}
+
+#[test]
+fn full_range() {
+    let map = cmap!(
+        '\0' => 1;
+        char::MAX => 2;
+    );
+    assert_eq!(Some(0x110000 - 0x800), map.code_point_span());
+}
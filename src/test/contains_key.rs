@@ -0,0 +1,46 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert!(!map.contains_key(2));
+}
+
+#[test]
+fn before_first_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert!(!map.contains_key(0));
+}
+
+#[test]
+fn in_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert!(map.contains_key(1));
+    assert!(map.contains_key(2));
+    assert!(map.contains_key(3));
+    assert!(map.contains_key(5));
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert!(!map.contains_key(4));
+}
+
+#[test]
+fn after_last_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert!(!map.contains_key(6));
+}
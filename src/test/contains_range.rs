@@ -0,0 +1,129 @@
+use crate::cmap;
+
+mod range {
+    use super::*;
+
+    #[test]
+    fn entire_slice() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(map.contains_range(3..6));
+    }
+
+    #[test]
+    fn middle_of_slice() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(map.contains_range(4..5));
+    }
+
+    #[test]
+    fn too_long() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(3..7));
+    }
+
+    #[test]
+    fn starts_too_early() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(2..6));
+    }
+
+    #[test]
+    fn starts_too_late() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(6..7));
+    }
+
+    #[test]
+    fn contains_gap() {
+        let map = cmap!(3 => 13; 5 => 15);
+        assert!(!map.contains_range(3..6));
+    }
+
+    #[test]
+    fn empty_range() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(4..4));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn backwards_range() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(4..3));
+    }
+}
+
+mod range_inclusive {
+    use super::*;
+
+    #[test]
+    fn entire_slice() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(map.contains_range(3..=5));
+    }
+
+    #[test]
+    fn single_key() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(map.contains_range(4..=4));
+    }
+
+    #[test]
+    fn too_long() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(3..=6));
+    }
+
+    #[test]
+    fn contains_gap() {
+        let map = cmap!(3 => 13; 5 => 15);
+        assert!(!map.contains_range(3..=5));
+    }
+}
+
+mod range_from {
+    use super::*;
+
+    #[test]
+    fn part_of_slice() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(map.contains_range(4..));
+    }
+
+    #[test]
+    fn starts_too_early() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(2..));
+    }
+
+    #[test]
+    fn starts_too_late() {
+        let map = cmap!(3 => 13, 14, 15);
+        assert!(!map.contains_range(6..));
+    }
+}
+
+#[test]
+fn matches_get_slice() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+        7 => 17, 18;
+    );
+    for start in 0..10 {
+        assert_eq!(
+            map.get_slice(start..).is_some(),
+            map.contains_range(start..)
+        );
+        for end in 0..11 {
+            assert_eq!(
+                map.get_slice(start..end).is_some(),
+                map.contains_range(start..end)
+            );
+            assert_eq!(
+                map.get_slice(start..=end).is_some(),
+                map.contains_range(start..=end)
+            );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::assert_map_same;
+use crate::cmap;
+
+#[test]
+fn non_overlapping() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        10 => 20;
+    );
+    assert!(map.copy_within(1, 7, 3));
+    assert_map_same(&map, [(1, vec![11, 12, 13]), (7, vec![11, 12, 13, 20])]);
+}
+
+#[test]
+fn overlapping_forward() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    assert!(map.copy_within(1, 2, 3));
+    assert_map_same(&map, [(1, vec![11, 11, 12, 13])]);
+}
+
+#[test]
+fn overlapping_backward() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    assert!(map.copy_within(2, 1, 3));
+    assert_map_same(&map, [(1, vec![12, 13, 14, 14])]);
+}
+
+#[test]
+fn source_not_present() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert!(!map.copy_within(2, 10, 3));
+    assert_map_same(&map, [(1, vec![11, 12, 13]), (5, vec![15])]);
+}
+
+#[test]
+fn zero_length() {
+    let mut map = cmap!(1 => 11);
+    assert!(map.copy_within(5, 10, 0));
+    assert_map_same(&map, [(1, vec![11])]);
+}
@@ -0,0 +1,52 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty_map() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(0, map.count_in_range(..));
+}
+
+#[test]
+fn range_in_gap() {
+    let map = cmap!(1 => 11; 5 => 15);
+    assert_eq!(0, map.count_in_range(2..5));
+}
+
+#[test]
+fn within_region() {
+    let map = cmap!(1 => 11, 12, 13, 14);
+    assert_eq!(2, map.count_in_range(2..4));
+    assert_eq!(4, map.count_in_range(..));
+}
+
+#[test]
+fn clips_first_and_last_regions() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16;
+        10 => 20, 21, 22;
+    );
+    assert_eq!(3, map.count_in_range(3..=10));
+    assert_eq!(5, map.count_in_range(2..12));
+    assert_eq!(7, map.count_in_range(..));
+}
+
+#[test]
+fn skips_gap() {
+    let map = cmap!(
+        1 => 11, 12;
+        10 => 20, 21;
+    );
+    assert_eq!(2, map.count_in_range(2..=10));
+    assert_eq!(
+        2,
+        map.count_in_range((std::ops::Bound::Excluded(1), std::ops::Bound::Excluded(11)))
+    );
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn backwards_range() {
+    let map = cmap!(1 => 11, 12, 13);
+    assert_eq!(0, map.count_in_range(3..1));
+}
@@ -0,0 +1,30 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.coverage_ratio());
+}
+
+#[test]
+fn contiguous() {
+    let map = cmap!(3 => 13, 14, 15);
+    assert_eq!(Some(1.0), map.coverage_ratio());
+}
+
+#[test]
+fn half() {
+    let map = cmap!(
+        0 => 10, 11;
+        6 => 16, 17;
+    );
+    assert_eq!(Some(0.5), map.coverage_ratio());
+}
+
+#[test]
+fn quarter() {
+    let map = cmap!(0 => 10; 3 => 13);
+    assert_eq!(Some(0.5), map.coverage_ratio());
+    let map = cmap!(0 => 10; 7 => 17);
+    assert_eq!(Some(0.25), map.coverage_ratio());
+}
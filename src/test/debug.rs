@@ -1,4 +1,4 @@
-use crate::cmap;
+use crate::{cmap, ContiguousMap};
 
 #[test]
 fn must_compile() {
@@ -6,3 +6,27 @@ fn must_compile() {
     let map = cmap!(1 => 1, 2, 3);
     let _ = format!("{:?}", map);
 }
+
+#[test]
+fn empty() {
+    let map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    assert_eq!("{}", format!("{:?}", map));
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 5;
+    );
+    assert_eq!("{10: [0, 1, 2], 20: [5]}", format!("{:?}", map));
+}
+
+#[test]
+fn alternate() {
+    let map = cmap!(10 => 0, 1);
+    assert_eq!(
+        "{\n    10: [\n        0,\n        1,\n    ],\n}",
+        format!("{:#?}", map)
+    );
+}
@@ -0,0 +1,75 @@
+use super::{assert_de_iter_empty, assert_map_same};
+use crate::cmap;
+
+#[test]
+fn empty_range() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_de_iter_empty(map.drain_range(5..10));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn forward() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    let mut drain = map.drain_range(2..8);
+    assert_eq!(Some((2, 12)), drain.next());
+    assert_eq!(Some((3, 13)), drain.next());
+    assert_eq!(Some((6, 16)), drain.next());
+    assert_eq!(Some((7, 17)), drain.next());
+    assert_de_iter_empty(drain);
+    assert_map_same(&map, [(1, vec![11]), (8, vec![18])]);
+}
+
+#[test]
+fn reverse() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    let mut drain = map.drain_range(2..8);
+    assert_eq!(Some((7, 17)), drain.next_back());
+    assert_eq!(Some((2, 12)), drain.next());
+    assert_eq!(Some((6, 16)), drain.next_back());
+    assert_eq!(Some((3, 13)), drain.next_back());
+    assert_de_iter_empty(drain);
+}
+
+#[test]
+fn partially_consumed() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    let mut drain = map.drain_range(2..=6);
+    assert_eq!(Some((2, 12)), drain.next());
+    drop(drain);
+    assert_map_same(&map, [(1, vec![11]), (7, vec![17, 18])]);
+}
+
+#[test]
+fn fold() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    let sum: i32 = map.drain_range(..).map(|(_, value)| value).sum();
+    assert_eq!(87, sum);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn exact_size() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    let mut drain = map.drain_range(2..8);
+    assert_eq!(4, drain.len());
+    drain.next();
+    assert_eq!(3, drain.len());
+    drain.next_back();
+    assert_eq!((2, Some(2)), drain.size_hint());
+}
@@ -0,0 +1,163 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap, Entry};
+
+#[test]
+fn vacant_empty_map() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(&mut 10, map.entry(5).or_insert(10));
+    assert_map_same(&map, [(5, vec![10])]);
+}
+
+#[test]
+fn vacant_merges_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14, 15;
+    );
+    assert_eq!(&mut 13, map.entry(3).or_insert(13));
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14, 15])]);
+}
+
+#[test]
+fn vacant_or_insert_with() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(&mut 13, map.entry(3).or_insert_with(|| 13));
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+}
+
+#[test]
+fn vacant_or_default() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(&mut 0, map.entry(3).or_default());
+    assert_map_same(&map, [(1, vec![11, 12, 0])]);
+}
+
+#[test]
+fn occupied_or_default() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(&mut 12, map.entry(2).or_default());
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+}
+
+#[test]
+fn or_default_histogram() {
+    let mut map = ContiguousMap::new();
+    for sample in [3, 1, 2, 3, 5, 4, 3, 1] {
+        *map.entry(sample).or_default() += 1;
+    }
+    assert_map_same(&map, [(1, vec![2, 1, 3, 1, 1])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn occupied_or_insert() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    *map.entry(2).or_insert(0) += 10;
+    assert_map_same(&map, [(1, vec![11, 22, 13])]);
+}
+
+#[test]
+fn occupied_or_insert_with_not_called() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(&mut 12, map.entry(2).or_insert_with(|| panic!()));
+}
+
+#[test]
+fn and_modify_occupied() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.entry(3).and_modify(|v| *v += 1).or_insert(0);
+    assert_map_same(&map, [(1, vec![11, 12, 14])]);
+}
+
+#[test]
+fn and_modify_vacant() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.entry(4).and_modify(|v| *v += 1).or_insert(0);
+    assert_map_same(&map, [(1, vec![11, 12, 13, 0])]);
+}
+
+#[test]
+fn key() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(&2, map.entry(2).key());
+    assert_eq!(&7, map.entry(7).key());
+}
+
+#[test]
+fn occupied_get() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    match map.entry(2) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(&12, entry.get());
+            *entry.get_mut() = 20;
+            assert_eq!(&20, entry.get());
+        }
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+    assert_map_same(&map, [(1, vec![11, 20, 13])]);
+}
+
+#[test]
+fn occupied_remove() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    match map.entry(2) {
+        Entry::Occupied(entry) => assert_eq!(12, entry.remove()),
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+    assert_map_same(&map, [(1, vec![11]), (3, vec![13])]);
+}
+
+#[test]
+fn vacant_insert() {
+    let mut map = cmap!(1 => 11);
+    match map.entry(2) {
+        Entry::Occupied(_) => panic!("expected a vacant entry"),
+        Entry::Vacant(entry) => *entry.insert(12) += 1,
+    }
+    assert_map_same(&map, [(1, vec![11, 13])]);
+}
+
+#[test]
+fn occupied_key() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    match map.entry(2) {
+        Entry::Occupied(entry) => assert_eq!(&2, entry.key()),
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    }
+}
+
+#[test]
+fn vacant_key() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    match map.entry(5) {
+        Entry::Occupied(_) => panic!("expected a vacant entry"),
+        Entry::Vacant(entry) => assert_eq!(&5, entry.key()),
+    }
+}
+
+#[test]
+fn occupied_into_mut() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    let value = match map.entry(3) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    };
+    *value = 30;
+    assert_map_same(&map, [(1, vec![11, 12, 30])]);
+}
+
+#[test]
+fn occupied_remove_matches_map_remove() {
+    let mut map = cmap!(
+        1 => 11, 12, 13, 14;
+        10 => 20;
+    );
+    let mut expected = map.clone();
+    let removed = match map.entry(3) {
+        Entry::Occupied(entry) => entry.remove(),
+        Entry::Vacant(_) => panic!("expected an occupied entry"),
+    };
+    assert_eq!(expected.remove(3).unwrap(), removed);
+    assert_eq!(expected, map);
+    assert_map_same(&map, [(1, vec![11, 12]), (4, vec![14]), (10, vec![20])]);
+}
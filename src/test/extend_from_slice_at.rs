@@ -0,0 +1,131 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+/// Asserts that extend_from_slice_at() and insert_slice() produce the same map.
+fn assert_matches_insert_slice(map: &ContiguousMap<usize, i32>, start_key: usize, values: &[i32]) {
+    let mut expected = map.clone();
+    expected.insert_slice(start_key, values);
+    let mut actual = map.clone();
+    actual.extend_from_slice_at(start_key, values);
+    let expected_regions: Vec<(usize, Vec<i32>)> = expected
+        .iter_vec_ref()
+        .map(|(key, vec)| (*key, vec.clone()))
+        .collect();
+    crate::test_util::assert_regions(&actual, &expected_regions);
+    assert_eq!(expected.len(), actual.len());
+}
+
+#[test]
+fn empty_map() {
+    let mut map = ContiguousMap::new();
+    map.extend_from_slice_at(1, &[11, 12, 13]);
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn empty_slice() {
+    let mut map = cmap!(1 => 11);
+    map.extend_from_slice_at(5, &[]);
+    assert_map_same(&map, [(1, vec![11])]);
+}
+
+#[test]
+fn append_to_region() {
+    let mut map = cmap!(1 => 11, 12);
+    map.extend_from_slice_at(3, &[13, 14]);
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14])]);
+    assert_eq!(4, map.len());
+}
+
+#[test]
+fn overwrite_within_region() {
+    let mut map = cmap!(1 => 11, 12, 13, 14, 15);
+    map.extend_from_slice_at(2, &[20, 30]);
+    assert_map_same(&map, [(1, vec![11, 20, 30, 14, 15])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn merges_over_gaps_and_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14;
+        6 => 16, 17, 18;
+        20 => 30;
+    );
+    map.extend_from_slice_at(2, &[0, 0, 0, 0, 0, 0]);
+    assert_map_same(&map, [(1, vec![11, 0, 0, 0, 0, 0, 0, 18]), (20, vec![30])]);
+    assert_eq!(9, map.len());
+}
+
+#[test]
+fn matches_insert_slice() {
+    let maps = [
+        ContiguousMap::new(),
+        cmap!(1 => 11, 12, 13),
+        cmap!(
+            1 => 11, 12;
+            4 => 14;
+            6 => 16, 17, 18;
+            10 => 20;
+        ),
+        cmap!(
+            0 => 10;
+            3 => 13, 14, 15, 16, 17, 18, 19;
+        ),
+    ];
+    for map in maps.iter() {
+        for start_key in 0..12 {
+            for len in 0..12 {
+                let values: Vec<i32> = (0..len).map(|i| 100 + i).collect();
+                assert_matches_insert_slice(map, start_key, &values);
+            }
+        }
+    }
+}
+
+#[test]
+fn overflow() {
+    let mut map = cmap!(250u8 => 0);
+    map.extend_from_slice_at(253u8, &[3, 4, 5, 6, 7]);
+    assert_eq!(Some(&[3, 4, 5][..]), map.get_slice(253u8..));
+    assert_eq!(4, map.len());
+    assert_eq!(2, map.num_contiguous_regions());
+}
+
+#[test]
+fn overflow_long_slice() {
+    let mut map = ContiguousMap::new();
+    map.extend_from_slice_at(u16::MAX - 2, &vec![7; 1_000_000]);
+    assert_eq!(Some(&[7, 7, 7][..]), map.get_slice(u16::MAX - 2..));
+    assert_eq!(3, map.len());
+
+    for start in 0..=4u8 {
+        let mut map = ContiguousMap::new();
+        map.extend_from_slice_at(u8::MAX - start, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(usize::from(start) + 1, map.len());
+    }
+}
+
+#[test]
+fn clone_panic_leaves_map_unchanged() {
+    #[derive(Debug, PartialEq)]
+    struct PanicOnClone(i32);
+
+    impl Clone for PanicOnClone {
+        fn clone(&self) -> Self {
+            assert_ne!(3, self.0, "cloned a poisoned value");
+            Self(self.0)
+        }
+    }
+
+    let mut map = ContiguousMap::new();
+    map.extend_from_slice_at(0u8, &[PanicOnClone(0), PanicOnClone(1)]);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.extend_from_slice_at(1u8, &[PanicOnClone(2), PanicOnClone(3)])
+    }));
+    assert!(result.is_err());
+    crate::test_util::assert_regions(&map, &[(0, vec![PanicOnClone(0), PanicOnClone(1)])]);
+    assert_eq!(2, map.len());
+}
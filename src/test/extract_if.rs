@@ -0,0 +1,102 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.extract_if(|_, _| true).next());
+}
+
+#[test]
+fn odd_keys() {
+    let mut map = cmap!(0 => 10, 11, 12, 13, 14, 15);
+    let extracted: Vec<(usize, i32)> = map.extract_if(|key, _| key % 2 == 1).collect();
+    assert_eq!(vec![(1, 11), (3, 13), (5, 15)], extracted);
+    assert_map_same(&map, [(0, vec![10]), (2, vec![12]), (4, vec![14])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn across_regions() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17;
+    );
+    let extracted: Vec<(usize, i32)> = map.extract_if(|_, value| *value >= 13).collect();
+    assert_eq!(vec![(3, 13), (6, 16), (7, 17)], extracted);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn modifies_kept_values() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    let extracted: Vec<(usize, i32)> = map
+        .extract_if(|key, value| {
+            *value += 100;
+            key == 2
+        })
+        .collect();
+    assert_eq!(vec![(2, 112)], extracted);
+    assert_map_same(&map, [(1, vec![111]), (3, vec![113])]);
+}
+
+#[test]
+fn dropped_early() {
+    let mut map = cmap!(
+        0 => 10, 11, 12, 13;
+        10 => 20, 21, 22;
+    );
+    assert_eq!(Some((1, 11)), map.extract_if(|key, _| key % 2 == 1).next());
+    // the first region is fully processed but the second region is not reached
+    assert_map_same(&map, [(0, vec![10]), (2, vec![12]), (10, vec![20, 21, 22])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn size_hint() {
+    let mut map = cmap!(
+        0 => 10, 11, 12, 13;
+        10 => 20;
+    );
+    let mut iter = map.extract_if(|key, _| key % 2 == 1);
+    assert_eq!((0, Some(5)), iter.size_hint());
+    iter.next();
+    assert_eq!((1, Some(4)), iter.size_hint());
+}
+
+#[test]
+fn large_dense_region() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(0, &vec![0; 100_000]);
+    assert_eq!(50_000, map.extract_if(|key, _| key % 2 == 0).count());
+    assert_eq!(50_000, map.len());
+    assert_eq!(50_000, map.num_contiguous_regions());
+}
+
+#[test]
+fn max_key() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(254u8, &[1, 2]);
+    let extracted: Vec<(u8, i32)> = map.extract_if(|_, _| true).collect();
+    assert_eq!(vec![(254, 1), (255, 2)], extracted);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn predicate_panic_keeps_length() {
+    let mut map = cmap!(
+        0 => 10, 11, 12, 13;
+        10 => 20;
+    );
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.extract_if(|key, _| {
+            assert_ne!(2, key);
+            key == 1
+        })
+        .count()
+    }));
+    assert!(result.is_err());
+    assert_map_same(&map, [(0, vec![10]), (2, vec![12, 13]), (10, vec![20])]);
+    assert_eq!(4, map.len());
+}
@@ -0,0 +1,41 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Vec::<(usize, usize)>::new(), map.fill_all_gaps(0));
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn one_region() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(Vec::<(usize, usize)>::new(), map.fill_all_gaps(0));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn multiple_gaps() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+        7 => 17, 18;
+    );
+    assert_eq!(vec![(3, 4), (6, 6)], map.fill_all_gaps(0));
+    assert_eq!(1, map.num_contiguous_regions());
+    assert_map_same(&map, [(1, vec![11, 12, 0, 0, 15, 0, 17, 18])]);
+}
+
+#[test]
+fn gap_too_large_leaves_map_unchanged() {
+    let mut map = cmap!(
+        0u128 => 1;
+        2 => 3;
+        u128::MAX => 4;
+    );
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| map.fill_all_gaps(0)));
+    assert!(result.is_err());
+    crate::test_util::assert_regions(&map, &[(0, vec![1]), (2, vec![3]), (u128::MAX, vec![4])]);
+    assert_eq!(3, map.len());
+}
@@ -0,0 +1,55 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty_map_unbounded() {
+    let mut map = ContiguousMap::new();
+    map.fill_gaps_with(.., |_| panic!("no keys to fill"));
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn empty_map_bounded() {
+    let mut map = ContiguousMap::new();
+    map.fill_gaps_with(2..5, |k| k as i32 * 10);
+    assert_map_same(&map, [(2, vec![20, 30, 40])]);
+}
+
+#[test]
+fn single_gap() {
+    let mut map = cmap!(0 => 0, 1; 5 => 5);
+    map.fill_gaps_with(0..=5, |k| k as i32 * 10);
+    assert_map_same(&map, [(0, vec![0, 1, 20, 30, 40, 5])]);
+}
+
+#[test]
+fn unbounded() {
+    let mut map = cmap!(0 => 0, 1; 5 => 5; 8 => 8);
+    map.fill_gaps_with(.., |_| -1);
+    assert_map_same(&map, [(0, vec![0, 1, -1, -1, -1, 5, -1, -1, 8])]);
+}
+
+#[test]
+fn partial_range() {
+    let mut map = cmap!(0 => 0, 1; 5 => 5; 8 => 8);
+    map.fill_gaps_with(3..7, |_| -1);
+    assert_map_same(
+        &map,
+        [(0, vec![0, 1]), (3, vec![-1, -1, 5, -1]), (8, vec![8])],
+    );
+}
+
+#[test]
+fn excluded_start() {
+    use std::ops::Bound;
+    let mut map = cmap!(0 => 0; 5 => 5);
+    map.fill_gaps_with((Bound::Excluded(2), Bound::Unbounded), |_| -1);
+    assert_map_same(&map, [(0, vec![0]), (3, vec![-1, -1, 5])]);
+}
+
+#[test]
+fn near_overflow() {
+    let mut map = cmap!(u8::MAX => 1);
+    map.fill_gaps_with(u8::MAX - 2.., |_| 0);
+    assert_eq!(vec![u8::MAX - 2..=u8::MAX], map.to_range_set());
+}
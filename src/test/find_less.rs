@@ -53,3 +53,11 @@ fn start_of_second_slice() {
     );
     assert_eq!(Index { key: 1, offset: 1 }, map.find_less(&4).unwrap());
 }
+
+#[test]
+fn large_offset() {
+    let map = cmap!(0u128 => 1, 2);
+    let index = map.find_less(&u128::MAX).unwrap();
+    assert_eq!(0, index.key);
+    assert_eq!(1, index.offset);
+}
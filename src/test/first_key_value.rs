@@ -0,0 +1,16 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.first_key_value());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(Some((1, &11)), map.first_key_value());
+}
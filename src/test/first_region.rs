@@ -0,0 +1,22 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.first_region());
+}
+
+#[test]
+fn single_region() {
+    let map = cmap!(3 => 13, 14);
+    assert_eq!(Some((3, &[13, 14][..])), map.first_region());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(Some((1, &[11, 12][..])), map.first_region());
+}
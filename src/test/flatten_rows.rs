@@ -0,0 +1,34 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, ContiguousMap<usize, i32>>::new();
+    assert_eq!(None, map.flatten_rows().next());
+}
+
+#[test]
+fn empty_rows() {
+    let map = cmap!(
+        1 => ContiguousMap::<usize, i32>::new(), ContiguousMap::new();
+    );
+    assert_eq!(None, map.flatten_rows().next());
+}
+
+#[test]
+fn gaps_in_both_dimensions() {
+    let map = cmap!(
+        0 => cmap!(0 => 1, 2; 5 => 3), cmap!(2 => 4);
+        10 => cmap!(7 => 5);
+    );
+    let flattened: Vec<_> = map.flatten_rows().collect();
+    assert_eq!(
+        vec![
+            ((0, 0), &1),
+            ((0, 1), &2),
+            ((0, 5), &3),
+            ((1, 2), &4),
+            ((10, 7), &5),
+        ],
+        flattened
+    );
+}
@@ -0,0 +1,31 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    map.for_each_value_mut(|_| panic!("no values to visit"));
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn visits_all_in_order() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    let mut visited = Vec::new();
+    map.for_each_value_mut(|value| visited.push(*value));
+    assert_eq!(vec![11, 12, 15, 16, 17, 20], visited);
+}
+
+#[test]
+fn mutate_all() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    map.for_each_value_mut(|value| *value *= 2);
+    assert_map_same(&map, [(1, vec![22, 24]), (5, vec![30, 32, 34])]);
+}
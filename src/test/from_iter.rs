@@ -0,0 +1,28 @@
+use super::assert_map_same;
+use crate::ContiguousMap;
+
+#[test]
+fn empty() {
+    let map: ContiguousMap<usize, i32> = std::iter::empty().collect();
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn sorted() {
+    let map: ContiguousMap<usize, i32> = vec![(1, 11), (2, 12), (5, 15)].into_iter().collect();
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn unsorted_merges() {
+    let map: ContiguousMap<usize, i32> = vec![(3, 13), (1, 11), (7, 17), (2, 12), (6, 16)]
+        .into_iter()
+        .collect();
+    assert_map_same(&map, [(1, vec![11, 12, 13]), (6, vec![16, 17])]);
+}
+
+#[test]
+fn duplicate_keys_overwrite() {
+    let map: ContiguousMap<usize, i32> = vec![(1, 11), (2, 12), (1, 21)].into_iter().collect();
+    assert_map_same(&map, [(1, vec![21, 12])]);
+}
@@ -0,0 +1,53 @@
+use super::assert_map_same;
+use crate::ContiguousMap;
+
+#[test]
+fn range() {
+    let map = ContiguousMap::from_range_with(0u8..5, |key| key as i32 * 10);
+    assert_eq!(1, map.num_contiguous_regions());
+    assert_eq!(5, map.len());
+    assert_eq!(Some(&[0, 10, 20, 30, 40][..]), map.get_slice(0u8..));
+}
+
+#[test]
+fn range_inclusive() {
+    let map = ContiguousMap::from_range_with(3..=5, |key| key as i32 + 10);
+    assert_map_same(&map, [(3, vec![13, 14, 15])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn range_from_is_not_filled() {
+    let mut calls = 0;
+    let map = ContiguousMap::from_range_with(253u8.., |key| {
+        calls += 1;
+        key
+    });
+    assert!(map.is_empty());
+    assert_eq!(0, calls);
+}
+
+#[test]
+fn range_inclusive_to_max_key() {
+    let map = ContiguousMap::from_range_with(253u8..=255, |key| key);
+    assert_eq!(Some(&[253, 254, 255][..]), map.get_slice(253u8..));
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn ascending_order() {
+    let mut keys = Vec::new();
+    let _ = ContiguousMap::from_range_with(2..6, |key: usize| keys.push(key));
+    assert_eq!(vec![2, 3, 4, 5], keys);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_range() {
+    let map = ContiguousMap::from_range_with(5..5, |key| key as i32);
+    assert_map_same(&map, []);
+    let map = ContiguousMap::from_range_with(5..2, |key| key as i32);
+    assert_map_same(&map, []);
+    let map = ContiguousMap::from_range_with(5..=4, |key| key as i32);
+    assert_map_same(&map, []);
+}
@@ -0,0 +1,39 @@
+use super::assert_map_same;
+use crate::ContiguousMap;
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::from_ranges(Vec::new(), 0);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn separate_ranges() {
+    let map = ContiguousMap::from_ranges(vec![0..=2, 10..=10], 7);
+    assert_map_same(&map, [(0, vec![7, 7, 7]), (10, vec![7])]);
+}
+
+#[test]
+fn adjacent_ranges_merge() {
+    let map = ContiguousMap::from_ranges(vec![3..=4, 0..=2], 7);
+    assert_map_same(&map, [(0, vec![7, 7, 7, 7, 7])]);
+}
+
+#[test]
+fn overlapping_ranges_merge() {
+    let map = ContiguousMap::from_ranges(vec![0..=3, 2..=5], 7);
+    assert_map_same(&map, [(0, vec![7, 7, 7, 7, 7, 7])]);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn backwards_range_ignored() {
+    let map = ContiguousMap::from_ranges(vec![5..=3, 0..=1], 7);
+    assert_map_same(&map, [(0, vec![7, 7])]);
+}
+
+#[test]
+fn near_overflow() {
+    let map = ContiguousMap::from_ranges(vec![u8::MAX - 1..=u8::MAX], 7);
+    assert_eq!(vec![u8::MAX - 1..=u8::MAX], map.to_range_set());
+}
@@ -0,0 +1,52 @@
+use super::assert_map_same;
+use crate::ContiguousMap;
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::from([]);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn separate_regions() {
+    let map = ContiguousMap::from([(1, vec![11, 12]), (5, vec![15])]);
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn adjacent_regions_merge() {
+    let map = ContiguousMap::from([(1, vec![11, 12]), (3, vec![13, 14])]);
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14])]);
+}
+
+#[test]
+fn empty_vec_dropped() {
+    let map = ContiguousMap::from([(1, vec![11]), (3, vec![]), (5, vec![15])]);
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15])]);
+}
+
+#[test]
+fn unsorted() {
+    let map = ContiguousMap::from([(5, vec![15]), (1, vec![11, 12])]);
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn overlap_overwrites() {
+    let map = ContiguousMap::from([(1, vec![11, 12, 13]), (2, vec![22, 23, 24])]);
+    assert_map_same(&map, [(1, vec![11, 22, 23, 24])]);
+}
+
+#[test]
+fn overflow_dropped() {
+    let map = ContiguousMap::from([(u8::MAX - 1, vec![1, 2, 3])]);
+    assert_eq!(2, map.len());
+    assert_eq!(Some(&[1, 2][..]), map.get_slice(u8::MAX - 1..));
+}
+
+#[test]
+fn overlap_inside_region() {
+    let map = ContiguousMap::from([(0, vec![10, 11, 12, 13, 14]), (2, vec![22])]);
+    assert_map_same(&map, [(0, vec![10, 11, 22, 13, 14])]);
+    assert_eq!(5, map.len());
+}
@@ -0,0 +1,49 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.get_contiguous_region(1));
+}
+
+#[test]
+fn start_of_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((1, &[11, 12, 13][..])), map.get_contiguous_region(1));
+}
+
+#[test]
+fn middle_of_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((1, &[11, 12, 13][..])), map.get_contiguous_region(2));
+}
+
+#[test]
+fn end_of_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((1, &[11, 12, 13][..])), map.get_contiguous_region(3));
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(None, map.get_contiguous_region(4));
+}
+
+#[test]
+fn before_first_region() {
+    let map = cmap!(1 => 11);
+    assert_eq!(None, map.get_contiguous_region(0));
+}
@@ -0,0 +1,30 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.get_contiguous_region_mut(1));
+}
+
+#[test]
+fn middle_of_region() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    let (key, slice) = map.get_contiguous_region_mut(2).unwrap();
+    assert_eq!(1, key);
+    assert_eq!(&mut [11, 12, 13], slice);
+    slice[0] = 21;
+    assert_map_same(&map, [(1, vec![21, 12, 13]), (5, vec![15])]);
+}
+
+#[test]
+fn in_gap() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(None, map.get_contiguous_region_mut(4));
+}
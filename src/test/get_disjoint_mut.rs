@@ -0,0 +1,57 @@
+use super::assert_map_same;
+use crate::cmap;
+
+#[test]
+fn same_region() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    let [a, b] = map.get_disjoint_mut([3, 1]).unwrap();
+    std::mem::swap(a, b);
+    assert_map_same(&map, [(1, vec![13, 12, 11])]);
+}
+
+#[test]
+fn different_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+        8 => 18, 19;
+    );
+    let [a, b] = map.get_disjoint_mut([2, 9]).unwrap();
+    *a += 100;
+    *b += 100;
+    assert_map_same(
+        &map,
+        [(1, vec![11, 112]), (5, vec![15]), (8, vec![18, 119])],
+    );
+}
+
+#[test]
+fn many() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15, 16;
+    );
+    for value in map.get_disjoint_mut([6, 1, 3, 5, 2]).unwrap() {
+        *value = -*value;
+    }
+    assert_map_same(&map, [(1, vec![-11, -12, -13]), (5, vec![-15, -16])]);
+}
+
+#[test]
+fn none_requested() {
+    let mut map = cmap!(1 => 11);
+    let [] = map.get_disjoint_mut([]).unwrap();
+}
+
+#[test]
+fn missing_key() {
+    let mut map = cmap!(1 => 11, 12; 5 => 15);
+    assert!(map.get_disjoint_mut([1, 3]).is_none());
+    assert!(map.get_disjoint_mut([0]).is_none());
+}
+
+#[test]
+fn duplicate_key() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert!(map.get_disjoint_mut([2, 1, 2]).is_none());
+}
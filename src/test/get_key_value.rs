@@ -0,0 +1,27 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.get_key_value(2));
+}
+
+#[test]
+fn before_first_region() {
+    let map = cmap!(10 => 0, 1, 2);
+    assert_eq!(None, map.get_key_value(9));
+}
+
+#[test]
+fn in_region() {
+    let map = cmap!(10 => 0, 1, 2);
+    assert_eq!(Some((10, &0)), map.get_key_value(10));
+    assert_eq!(Some((11, &1)), map.get_key_value(11));
+    assert_eq!(Some((12, &2)), map.get_key_value(12));
+}
+
+#[test]
+fn after_last_region() {
+    let map = cmap!(10 => 0, 1, 2);
+    assert_eq!(None, map.get_key_value(13));
+}
@@ -0,0 +1,51 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.get_nth(0));
+}
+
+#[test]
+fn first_region() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(Some((1, &11)), map.get_nth(0));
+    assert_eq!(Some((2, &12)), map.get_nth(1));
+}
+
+#[test]
+fn later_region() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    assert_eq!(Some((5, &15)), map.get_nth(2));
+    assert_eq!(Some((7, &17)), map.get_nth(4));
+    assert_eq!(Some((10, &20)), map.get_nth(5));
+}
+
+#[test]
+fn out_of_bounds() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(None, map.get_nth(3));
+    assert_eq!(None, map.get_nth(usize::MAX));
+}
+
+#[test]
+fn matches_iter() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    for (n, item) in map.iter().enumerate() {
+        assert_eq!(Some(item), map.get_nth(n));
+    }
+}
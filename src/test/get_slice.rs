@@ -59,6 +59,7 @@ mod range {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn backwards_range() {
         // range is invalid
         let map = cmap!(3 => 13, 14, 15);
@@ -118,6 +119,7 @@ mod range_to_inclusive {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn backwards_range() {
         // range is invalid
         let map = cmap!(3 => 13, 14, 15);
@@ -158,3 +160,29 @@ mod range_from {
         assert_eq!(None, map.get_slice(6..));
     }
 }
+
+mod wide_keys {
+    use crate::cmap;
+
+    #[test]
+    fn inclusive_length_overflows_usize() {
+        let mut map = cmap!(0u128 => 10, 11, 12);
+        assert_eq!(None, map.get_slice(0..=usize::MAX as u128));
+        assert_eq!(None, map.get_slice(0..u128::MAX));
+        assert_eq!(None, map.get_slice_mut(0..=usize::MAX as u128));
+        assert_eq!(Some(&[10, 11][..]), map.get_slice_with_len(0u128, 2));
+    }
+
+    #[test]
+    fn range_offset_overflows_usize() {
+        let map = cmap!(
+            0u128 => 10, 11;
+            u128::MAX => 12;
+        );
+        assert_eq!(None, map.get_slice(1..u128::MAX));
+        assert_eq!(Some(&[11][..]), map.get_slice(1u128..));
+        assert_eq!(Some(&[12][..]), map.get_slice(u128::MAX..));
+        let values: Vec<_> = map.range(1..).collect();
+        assert_eq!(vec![(1, &11), (u128::MAX, &12)], values);
+    }
+}
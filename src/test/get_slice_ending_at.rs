@@ -0,0 +1,49 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.get_slice_ending_at(3, 1));
+}
+
+#[test]
+fn entire_slice() {
+    let map = cmap!(3 => 13, 14, 15);
+    assert_eq!([13, 14, 15], map.get_slice_ending_at(5, 3).unwrap());
+}
+
+#[test]
+fn ending_mid_region() {
+    let map = cmap!(3 => 13, 14, 15, 16);
+    assert_eq!([14, 15], map.get_slice_ending_at(5, 2).unwrap());
+    assert_eq!([13, 14, 15], map.get_slice_ending_at(5, 3).unwrap());
+    assert_eq!([15], map.get_slice_ending_at(5, 1).unwrap());
+}
+
+#[test]
+fn zero_length() {
+    let map = cmap!(3 => 13, 14, 15);
+    assert_eq!(None, map.get_slice_ending_at(4, 0));
+}
+
+#[test]
+fn starts_before_region() {
+    let map = cmap!(3 => 13, 14, 15);
+    assert_eq!(None, map.get_slice_ending_at(4, 3));
+}
+
+#[test]
+fn ends_in_gap() {
+    let map = cmap!(3 => 13, 14; 7 => 17);
+    assert_eq!(None, map.get_slice_ending_at(5, 1));
+    assert_eq!(None, map.get_slice_ending_at(2, 1));
+}
+
+#[test]
+fn underflow() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(0u8, &[10, 11, 12]);
+    assert_eq!([10, 11], map.get_slice_ending_at(1u8, 2).unwrap());
+    assert_eq!(None, map.get_slice_ending_at(1u8, 3));
+    assert_eq!(None, map.get_slice_ending_at(0u8, usize::MAX));
+}
@@ -60,6 +60,7 @@ mod range {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn backwards_range() {
         // range is invalid
         let mut map = cmap!(3 => 13, 14, 15);
@@ -119,6 +120,7 @@ mod range_to_inclusive {
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn backwards_range() {
         // range is invalid
         let mut map = cmap!(3 => 13, 14, 15);
@@ -0,0 +1,48 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn no_requests() {
+    let map = cmap!(1 => 11);
+    assert!(map.get_slices(Vec::new()).is_empty());
+}
+
+#[test]
+fn empty_map() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(vec![None, None], map.get_slices(vec![(1, 1), (2, 3)]));
+}
+
+#[test]
+fn mixed_requests() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        7 => 17, 18;
+    );
+    assert_eq!(
+        vec![
+            Some(&[17, 18][..]),
+            None,
+            Some(&[12, 13][..]),
+            None,
+            None,
+            Some(&[11][..]),
+        ],
+        map.get_slices(vec![(7, 2), (3, 2), (2, 2), (5, 1), (1, 0), (1, 1)])
+    );
+}
+
+#[test]
+fn matches_get_slice_with_len() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        7 => 17, 18;
+    );
+    let requests: Vec<(usize, usize)> = (0..10)
+        .flat_map(|start| (0..5).map(move |len| (start, len)))
+        .collect();
+    let expected: Vec<Option<&[i32]>> = requests
+        .iter()
+        .map(|(start, len)| map.get_slice_with_len(start, *len))
+        .collect();
+    assert_eq!(expected, map.get_slices(requests));
+}
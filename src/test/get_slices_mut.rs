@@ -0,0 +1,66 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.get_slices_mut(1..3));
+}
+
+#[test]
+fn single_region() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    let mut slices = map.get_slices_mut(2..=3).unwrap();
+    assert_eq!(1, slices.len());
+    let (key, slice) = &mut slices[0];
+    assert_eq!(2, *key);
+    assert_eq!([12, 13], **slice);
+    slice[0] = 20;
+    assert_map_same(&map, [(1, vec![11, 20, 13, 14])]);
+}
+
+#[test]
+fn merged_regions() {
+    let mut map = cmap!(1 => 11, 12; 4 => 14);
+    map.insert(3, 13);
+    let slices = map.get_slices_mut(1..).unwrap();
+    assert_eq!(vec![(1, &mut [11, 12, 13, 14][..])], slices);
+}
+
+#[test]
+fn gap_in_range() {
+    let mut map = cmap!(1 => 11, 12; 4 => 14, 15);
+    assert_eq!(None, map.get_slices_mut(1..6));
+    assert_eq!(None, map.get_slices_mut(2..=4));
+}
+
+#[test]
+fn past_region_end() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(None, map.get_slices_mut(1..4));
+}
+
+#[test]
+fn unbounded_stops_at_gap() {
+    let mut map = cmap!(1 => 11, 12; 4 => 14, 15);
+    let slices = map.get_slices_mut(2..).unwrap();
+    assert_eq!(vec![(2, &mut [12][..])], slices);
+    assert_eq!(None, map.get_slices_mut(3..));
+}
+
+#[test]
+fn split_per_region() {
+    let mut map = ContiguousMap::from_raw_regions(vec![(1, vec![11, 12]), (3, vec![13, 14, 15])]);
+    let mut slices = map.get_slices_mut(2..=4).unwrap();
+    assert_eq!(vec![(2, &mut [12][..]), (3, &mut [13, 14][..])], slices);
+    slices[0].1[0] = 20;
+    slices[1].1[1] = 40;
+    assert_eq!(Some(&20), map.get(2));
+    assert_eq!(Some(&40), map.get(4));
+}
+
+#[test]
+fn empty_range() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(None, map.get_slices_mut(1..1));
+}
@@ -0,0 +1,41 @@
+use crate::{cmap, ContiguousMap};
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+fn hash_of(map: &ContiguousMap<usize, i32>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    map.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn different_insertion_order() {
+    let mut map1 = ContiguousMap::new();
+    for key in [1, 2, 3, 7, 8] {
+        map1.insert(key, key as i32 + 10);
+    }
+    let mut map2 = ContiguousMap::new();
+    for key in [8, 3, 1, 7, 2] {
+        map2.insert(key, key as i32 + 10);
+    }
+    assert_eq!(map1, map2);
+    assert_eq!(hash_of(&map1), hash_of(&map2));
+}
+
+#[test]
+fn value_different() {
+    let map1 = cmap!(10 => 1, 2, 3);
+    let map2 = cmap!(10 => 1, 2, 5);
+    assert_ne!(hash_of(&map1), hash_of(&map2));
+}
+
+#[test]
+fn hash_set() {
+    let mut set = HashSet::new();
+    assert!(set.insert(cmap!(10 => 1, 2, 3)));
+    assert!(!set.insert(cmap!(10 => 1; 11 => 2, 3)));
+    assert!(set.insert(cmap!(11 => 1, 2, 3)));
+    assert_eq!(2, set.len());
+}
@@ -0,0 +1,41 @@
+use super::assert_map_same;
+use crate::cmap;
+
+#[test]
+fn present() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(11, map[1]);
+    assert_eq!(12, map[&2]);
+    assert_eq!(15, map[5]);
+}
+
+#[test]
+#[should_panic(expected = "key not found")]
+fn in_gap() {
+    let map = cmap!(1 => 11; 5 => 15);
+    let _ = map[3];
+}
+
+#[test]
+#[should_panic(expected = "key not found")]
+fn empty() {
+    let map = crate::ContiguousMap::<usize, i32>::new();
+    let _ = map[0];
+}
+
+#[test]
+fn index_mut() {
+    let mut map = cmap!(1 => 11, 12);
+    map[2] += 100;
+    assert_map_same(&map, [(1, vec![11, 112])]);
+}
+
+#[test]
+#[should_panic(expected = "key not found")]
+fn index_mut_in_gap() {
+    let mut map = cmap!(1 => 11; 5 => 15);
+    map[3] = 13;
+}
@@ -0,0 +1,35 @@
+use crate::{test_util::assert_regions, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map: ContiguousMap<usize, String> = ContiguousMap::new();
+    map.insert_many(Vec::new());
+    assert_regions(&map, &[]);
+}
+
+#[test]
+fn fills_gap() {
+    let mut map = ContiguousMap::new();
+    map.insert_many(vec![(1, "a".to_string()), (4, "d".to_string())]);
+    assert_eq!(2, map.num_contiguous_regions());
+    map.insert_many(vec![(3, "c".to_string()), (2, "b".to_string())]);
+    assert_regions(
+        &map,
+        &[(
+            1,
+            vec![
+                "a".to_string(),
+                "b".to_string(),
+                "c".to_string(),
+                "d".to_string(),
+            ],
+        )],
+    );
+}
+
+#[test]
+fn overwrites() {
+    let mut map = ContiguousMap::new();
+    map.insert_many(vec![(1, Box::new(1)), (2, Box::new(2)), (1, Box::new(3))]);
+    assert_regions(&map, &[(1, vec![Box::new(3), Box::new(2)])]);
+}
@@ -0,0 +1,45 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap, CounterFullError};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Ok(0), map.insert_next(10));
+    assert_map_same(&map, [(0, vec![10])]);
+}
+
+#[test]
+fn ascending_keys() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Ok(0), map.insert_next(10));
+    assert_eq!(Ok(1), map.insert_next(11));
+    assert_eq!(Ok(2), map.insert_next(12));
+    assert_map_same(&map, [(0, vec![10, 11, 12])]);
+}
+
+#[test]
+fn after_last_region() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(Ok(6), map.insert_next(16));
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15, 16])]);
+}
+
+#[test]
+fn full() {
+    let mut map = cmap!(u8::MAX - 1 => 1);
+    assert_eq!(Ok(u8::MAX), map.insert_next(2));
+    assert_eq!(Err(CounterFullError), map.insert_next(3));
+    assert_eq!(2, map.len());
+    assert_eq!(Some(&2), map.get(u8::MAX));
+}
+
+#[test]
+fn signed_starts_at_lowest() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Ok(i32::MIN), map.insert_next(10));
+    assert_eq!(Ok(i32::MIN + 1), map.insert_next(11));
+    assert_eq!(Some(&[10, 11][..]), map.get_slice(i32::MIN..));
+}
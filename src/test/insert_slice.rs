@@ -38,3 +38,21 @@ fn merge() {
     map.insert_slice(13, &[7, 8, 9]);
     assert_map_same(&map, [(10, vec![1, 2, 3, 7, 8, 9, 4, 5, 6])]);
 }
+
+#[test]
+fn reserves_capacity() {
+    let values: Vec<i32> = (0..10_000).collect();
+    let mut map = ContiguousMap::new();
+    map.insert_slice(0, &values);
+    // room for the entire slice is reserved up front
+    assert!(map.map[&0].capacity() >= 10_000);
+    assert_map_same(&map, [(0, values)]);
+}
+
+#[test]
+fn reserves_capacity_partial_overwrite() {
+    let mut map = cmap!(10 => 1, 2, 3);
+    map.insert_slice(11, &[4, 5, 6, 7]);
+    assert!(map.map[&10].capacity() >= 5);
+    assert_map_same(&map, [(10, vec![1, 4, 5, 6, 7])]);
+}
@@ -58,3 +58,39 @@ fn double_ended() {
     assert_eq!((20, 0), iter.next_back().unwrap());
     assert_de_iter_empty(iter);
 }
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+        30 => 0,
+    );
+    let mut iter = map.into_iter();
+    assert_eq!(6, iter.len());
+    assert_eq!((6, Some(6)), iter.size_hint());
+    iter.next();
+    assert_eq!(5, iter.len());
+    iter.next_back();
+    assert_eq!(4, iter.len());
+    iter.next_back();
+    iter.next_back();
+    assert_eq!(2, iter.len());
+    iter.next();
+    iter.next();
+    assert_eq!(0, iter.len());
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    assert_eq!(0, iter.len());
+}
+
+#[test]
+fn collect_preallocates() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+    );
+    let vec: Vec<_> = map.into_iter().collect();
+    assert_eq!(5, vec.len());
+    assert_eq!(5, vec.capacity());
+}
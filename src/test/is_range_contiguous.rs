@@ -0,0 +1,64 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty_map() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert!(!map.is_range_contiguous(1..3));
+    assert!(!map.is_range_contiguous(..));
+}
+
+#[test]
+fn fully_covered() {
+    let map = cmap!(
+        1 => 11, 12, 13, 14;
+        10 => 20;
+    );
+    assert!(map.is_range_contiguous(1..5));
+    assert!(map.is_range_contiguous(2..=3));
+    assert!(map.is_range_contiguous(10..=10));
+    assert!(map.is_range_contiguous((std::ops::Bound::Excluded(1), std::ops::Bound::Included(4))));
+}
+
+#[test]
+fn spans_gap() {
+    let map = cmap!(
+        1 => 11, 12;
+        4 => 14, 15;
+    );
+    assert!(!map.is_range_contiguous(1..6));
+    assert!(!map.is_range_contiguous(2..=4));
+}
+
+#[test]
+fn past_region_end() {
+    let map = cmap!(1 => 11, 12, 13);
+    assert!(!map.is_range_contiguous(2..5));
+    assert!(!map.is_range_contiguous(2..));
+}
+
+#[test]
+fn before_region_start() {
+    let map = cmap!(1 => 11, 12, 13);
+    assert!(!map.is_range_contiguous(0..3));
+    assert!(!map.is_range_contiguous(..3));
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_range() {
+    let map = cmap!(1 => 11, 12, 13);
+    assert!(map.is_range_contiguous(5..5));
+    assert!(map.is_range_contiguous(5..2));
+    assert!(map.is_range_contiguous(..i32::MIN));
+}
+
+#[test]
+fn unbounded() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(0u8, &[0, 1, 2]);
+    assert!(map.is_range_contiguous(..3));
+    assert!(!map.is_range_contiguous(..));
+    map.insert_slice(3u8, &[0; 253]);
+    assert!(map.is_range_contiguous(..));
+    assert!(map.is_range_contiguous(100..));
+}
@@ -58,3 +58,162 @@ fn double_ended() {
     assert_eq!((20, &0), iter.next_back().unwrap());
     assert_de_iter_empty(iter);
 }
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+        30 => 0,
+    );
+    let mut iter = map.iter();
+    assert_eq!(6, iter.len());
+    assert_eq!((6, Some(6)), iter.size_hint());
+    iter.next();
+    assert_eq!(5, iter.len());
+    iter.next_back();
+    assert_eq!(4, iter.len());
+    iter.next_back();
+    iter.next_back();
+    assert_eq!(2, iter.len());
+    iter.next();
+    iter.next();
+    assert_eq!(0, iter.len());
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    assert_eq!(0, iter.len());
+}
+
+#[test]
+fn collect_preallocates() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+    );
+    let vec: Vec<_> = map.iter().collect();
+    assert_eq!(5, vec.len());
+    assert_eq!(5, vec.capacity());
+}
+
+mod key_clones {
+    use crate::{ContiguousMap, ToIndex, TryFromIndex};
+    use std::cell::Cell;
+
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Key that counts how many times it has been cloned on this thread.
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct CountedKey(u32);
+
+    impl Clone for CountedKey {
+        fn clone(&self) -> Self {
+            CLONES.with(|clones| clones.set(clones.get() + 1));
+            Self(self.0)
+        }
+    }
+
+    impl ToIndex for CountedKey {
+        type Index = u32;
+        fn to_index(&self) -> Self::Index {
+            self.0
+        }
+    }
+
+    impl TryFromIndex for CountedKey {
+        fn try_from_index(index: Self::Index) -> Option<Self> {
+            Some(Self(index))
+        }
+    }
+
+    fn map() -> ContiguousMap<CountedKey, i32> {
+        let mut map = ContiguousMap::new();
+        map.insert_slice(CountedKey(10), &[0, 1, 2, 3]);
+        map.insert_slice(CountedKey(20), &[0, 1]);
+        map
+    }
+
+    fn reset_clones() {
+        CLONES.with(|clones| clones.set(0));
+    }
+
+    fn num_clones() -> usize {
+        CLONES.with(|clones| clones.get())
+    }
+
+    #[test]
+    fn forward_clones_once_per_region() {
+        let map = map();
+        reset_clones();
+        let keys: Vec<u32> = map.iter().map(|(key, _)| key.0).collect();
+        assert_eq!(vec![10, 11, 12, 13, 20, 21], keys);
+        assert_eq!(2, num_clones());
+    }
+
+    #[test]
+    fn reverse_clones_once_per_region() {
+        let map = map();
+        reset_clones();
+        let keys: Vec<u32> = map.iter().rev().map(|(key, _)| key.0).collect();
+        assert_eq!(vec![21, 20, 13, 12, 11, 10], keys);
+        assert_eq!(2, num_clones());
+    }
+
+    #[test]
+    fn owning_never_clones() {
+        let map = map();
+        reset_clones();
+        let keys: Vec<u32> = map.into_iter().map(|(key, _)| key.0).collect();
+        assert_eq!(vec![10, 11, 12, 13, 20, 21], keys);
+        assert_eq!(0, num_clones());
+    }
+
+    /// Times iterating over a million entries in one region.
+    /// The keys are only cloned once per region rather than once per value,
+    /// which is compared against a loop that clones every key as iteration used to.
+    ///
+    /// Run with `cargo test --release -- --ignored --nocapture bench_million_entries`.
+    #[test]
+    #[ignore]
+    fn bench_million_entries() {
+        use std::hint::black_box;
+        use std::time::Instant;
+
+        const LEN: u32 = 1_000_000;
+        let mut map = ContiguousMap::new();
+        map.insert_slice(CountedKey(0), &vec![0; LEN as usize]);
+        let mut copy_map = ContiguousMap::new();
+        copy_map.insert_slice(0u32, &vec![0; LEN as usize]);
+
+        let start = Instant::now();
+        for entry in copy_map.iter() {
+            black_box(entry);
+        }
+        println!("u32 keys: {:?}", start.elapsed());
+
+        reset_clones();
+        let start = Instant::now();
+        for entry in map.iter() {
+            black_box(entry);
+        }
+        println!(
+            "counted keys: {:?}, {} clones",
+            start.elapsed(),
+            num_clones()
+        );
+        assert_eq!(1, num_clones());
+
+        reset_clones();
+        let start = Instant::now();
+        for (key, value) in map.iter() {
+            black_box((key.clone(), value));
+        }
+        println!(
+            "counted keys cloned per value: {:?}, {} clones",
+            start.elapsed(),
+            num_clones()
+        );
+        assert_eq!(LEN as usize + 1, num_clones());
+    }
+}
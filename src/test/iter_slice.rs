@@ -49,3 +49,23 @@ fn double_ended() {
     assert_eq!((&20, &[0, 1][..]), iter.next().unwrap());
     assert_de_iter_empty(iter);
 }
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+        30 => 0,
+    );
+    let mut iter = map.iter_slice();
+    assert_eq!(3, iter.len());
+    assert_eq!((3, Some(3)), iter.size_hint());
+    iter.next();
+    assert_eq!(2, iter.len());
+    iter.next_back();
+    assert_eq!(1, iter.len());
+    iter.next();
+    assert_eq!(0, iter.len());
+    assert!(iter.next().is_none());
+    assert_eq!(0, iter.len());
+}
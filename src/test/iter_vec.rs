@@ -49,3 +49,60 @@ fn double_ended() {
     assert_eq!((20, vec![0, 1]), iter.next().unwrap());
     assert_de_iter_empty(iter);
 }
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0, 1;
+        30 => 0,
+    );
+    let mut iter = map.iter_vec();
+    assert_eq!(3, iter.len());
+    assert_eq!((3, Some(3)), iter.size_hint());
+    iter.next();
+    assert_eq!(2, iter.len());
+    iter.next_back();
+    assert_eq!(1, iter.len());
+    iter.next();
+    assert_eq!(0, iter.len());
+    assert!(iter.next().is_none());
+    assert_eq!(0, iter.len());
+}
+
+#[test]
+fn flatten_unconsumed() {
+    let map = cmap!(
+        10 => 0, 1;
+        20 => 2;
+    );
+    let items: Vec<(usize, i32)> = map.iter_vec().flatten().collect();
+    assert_eq!(vec![(10, 0), (11, 1), (20, 2)], items);
+}
+
+#[test]
+fn flatten_partially_consumed() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 3, 4;
+        30 => 5;
+        40 => 6, 7;
+    );
+    let mut iter = map.iter_vec();
+    assert_eq!((10, vec![0, 1, 2]), iter.next().unwrap());
+    assert_eq!((40, vec![6, 7]), iter.next_back().unwrap());
+    let iter = iter.flatten();
+    assert_eq!(3, iter.len());
+    let items: Vec<(usize, i32)> = iter.collect();
+    assert_eq!(vec![(20, 3), (21, 4), (30, 5)], items);
+}
+
+#[test]
+fn flatten_exhausted() {
+    let map = cmap!(10 => 0, 1);
+    let mut iter = map.iter_vec();
+    iter.next();
+    let iter = iter.flatten();
+    assert_eq!(0, iter.len());
+    assert_de_iter_empty(iter);
+}
@@ -0,0 +1,34 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.iter_vec_ref().next());
+}
+
+#[test]
+fn matches_regions() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    let regions: Vec<(&usize, &Vec<i32>)> = map.iter_vec_ref().collect();
+    assert_eq!(vec![(&1, &vec![11, 12]), (&5, &vec![15, 16, 17])], regions);
+}
+
+#[test]
+fn exposes_capacity() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    map.region_pop_back(1);
+    let (_, vec) = map.iter_vec_ref().next().unwrap();
+    assert_eq!(3, vec.len());
+    assert!(vec.capacity() >= 4);
+}
+
+#[test]
+fn map_not_consumed() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(1, map.iter_vec_ref().count());
+    map.insert(3, 13);
+    assert_eq!(Some(&[11, 12, 13][..]), map.get_slice(1..));
+}
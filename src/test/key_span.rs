@@ -0,0 +1,40 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.key_span());
+}
+
+#[test]
+fn single_region() {
+    let map = cmap!(3 => 13, 14, 15);
+    assert_eq!(Some(3), map.key_span());
+}
+
+#[test]
+fn with_gap() {
+    let map = cmap!(
+        3 => 13, 14;
+        10 => 20, 21;
+    );
+    assert_eq!(Some(9), map.key_span());
+}
+
+#[test]
+fn too_large() {
+    let mut map = ContiguousMap::new();
+    map.insert(0u128, 1);
+    map.insert(u128::MAX, 2);
+    assert_eq!(None, map.key_span());
+}
+
+#[test]
+fn entire_key_space() {
+    let mut map = ContiguousMap::new();
+    map.insert(1, 1);
+    map.insert(usize::MAX, 2);
+    assert_eq!(Some(usize::MAX), map.key_span());
+    map.insert(0, 0);
+    assert_eq!(None, map.key_span());
+}
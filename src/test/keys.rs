@@ -0,0 +1,34 @@
+use super::assert_de_iter_empty;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_de_iter_empty(map.keys());
+}
+
+#[test]
+fn forward() {
+    let map = cmap!(10 => 0, 1; 20 => 0);
+    assert_eq!(vec![10, 11, 20], map.keys().collect::<Vec<_>>());
+}
+
+#[test]
+fn reverse() {
+    let map = cmap!(10 => 0, 1; 20 => 0);
+    assert_eq!(vec![20, 11, 10], map.keys().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0;
+    );
+    let mut iter = map.keys();
+    assert_eq!(4, iter.len());
+    assert_eq!((4, Some(4)), iter.size_hint());
+    iter.next();
+    iter.next_back();
+    assert_eq!(2, iter.len());
+}
@@ -0,0 +1,22 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.last_key_value());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(Some((7, &17)), map.last_key_value());
+}
+
+#[test]
+fn near_overflow() {
+    let map = cmap!(u8::MAX - 1 => 1, 2);
+    assert_eq!(Some((u8::MAX, &2)), map.last_key_value());
+}
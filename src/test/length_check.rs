@@ -0,0 +1,40 @@
+//! The cached length is cross-checked after mutations in debug builds.
+#![cfg(debug_assertions)]
+
+use crate::cmap;
+
+#[test]
+#[should_panic(expected = "cached length does not match")]
+fn insert() {
+    let mut map = cmap!(1 => 11, 12);
+    map.set_cached_length(5);
+    map.insert(3, 13);
+}
+
+#[test]
+#[should_panic(expected = "cached length does not match")]
+fn remove() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.set_cached_length(5);
+    map.remove(2);
+}
+
+#[test]
+#[should_panic(expected = "cached length does not match")]
+fn clear_range() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16;
+    );
+    map.set_cached_length(10);
+    map.clear_range(2..6);
+}
+
+#[test]
+fn consistent() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.insert(4, 14);
+    map.remove(2);
+    map.clear_range(3..=3);
+    assert_eq!(2, map.len());
+}
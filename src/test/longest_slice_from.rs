@@ -0,0 +1,43 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.longest_slice_from(1));
+}
+
+#[test]
+fn region_start() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16;
+    );
+    assert_eq!(Some((1, &[11, 12, 13][..])), map.longest_slice_from(1));
+}
+
+#[test]
+fn mid_region() {
+    let map = cmap!(
+        1 => 11, 12, 13, 14;
+        8 => 18;
+    );
+    assert_eq!(Some((2, &[12, 13, 14][..])), map.longest_slice_from(2));
+    assert_eq!(Some((4, &[14][..])), map.longest_slice_from(4));
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12;
+        6 => 16;
+    );
+    assert_eq!(None, map.longest_slice_from(3));
+    assert_eq!(None, map.longest_slice_from(5));
+}
+
+#[test]
+fn before_and_after() {
+    let map = cmap!(3 => 13, 14);
+    assert_eq!(None, map.longest_slice_from(0));
+    assert_eq!(None, map.longest_slice_from(5));
+}
@@ -0,0 +1,40 @@
+use crate::{cmap, test_util::assert_regions, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    let map = map.map_values(|value| value.to_string());
+    assert_regions(&map, &[]);
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    let map = map.map_values(|value| value.to_string());
+    assert_eq!(3, map.len());
+    assert_regions(
+        &map,
+        &[
+            (1, vec!["11".to_string(), "12".to_string()]),
+            (5, vec!["15".to_string()]),
+        ],
+    );
+}
+
+#[test]
+fn ascending_order() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    let mut order = Vec::new();
+    let map = map.map_values(|value| {
+        order.push(value);
+        order.len()
+    });
+    assert_eq!(vec![11, 12, 15], order);
+    assert_regions(&map, &[(1, vec![1, 2]), (5, vec![3])]);
+}
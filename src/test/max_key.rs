@@ -0,0 +1,23 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.max_key());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        3 => 13, 14;
+        7 => 17, 18, 19;
+    );
+    assert_eq!(Some(9), map.max_key());
+}
+
+#[test]
+fn highest_key() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(254u8, &[1, 2]);
+    assert_eq!(Some(255), map.max_key());
+}
@@ -0,0 +1,44 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn into_empty() {
+    let mut map = ContiguousMap::new();
+    map.merge_with(cmap!(1 => 11, 12), |_, _| panic!("no collisions"));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn from_empty() {
+    let mut map = cmap!(1 => 11, 12);
+    map.merge_with(ContiguousMap::new(), |_, _| panic!("no collisions"));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn overlapping_runs() {
+    let mut map = cmap!(1 => 1, 1, 1, 1);
+    map.merge_with(cmap!(3 => 10, 10, 10, 10), |existing, incoming| {
+        *existing += incoming
+    });
+    assert_map_same(&map, [(1, vec![1, 1, 11, 11, 10, 10])]);
+}
+
+#[test]
+fn fills_gap() {
+    let mut map = cmap!(
+        1 => 1, 1;
+        5 => 1, 1;
+    );
+    map.merge_with(cmap!(2 => 10, 10, 10, 10), |existing, incoming| {
+        *existing += incoming
+    });
+    assert_map_same(&map, [(1, vec![1, 11, 10, 10, 11, 1])]);
+}
+
+#[test]
+fn disjoint() {
+    let mut map = cmap!(1 => 11);
+    map.merge_with(cmap!(5 => 15), |_, _| panic!("no collisions"));
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15])]);
+}
@@ -0,0 +1,16 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.min_key());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        3 => 13, 14;
+        7 => 17, 18, 19;
+    );
+    assert_eq!(Some(3), map.min_key());
+}
@@ -0,0 +1,35 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn occupied() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.modify_or_insert(2, || panic!("default called"), |v| *v += 1);
+    assert_map_same(&map, [(1, vec![11, 13, 13])]);
+}
+
+#[test]
+fn vacant_empty_map() {
+    let mut map = ContiguousMap::new();
+    map.modify_or_insert(5, || 0, |v| *v += 1);
+    assert_map_same(&map, [(5, vec![1])]);
+}
+
+#[test]
+fn vacant_merges_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14, 15;
+    );
+    map.modify_or_insert(3, || 12, |v| *v += 1);
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14, 15])]);
+}
+
+#[test]
+fn counter() {
+    let mut map = ContiguousMap::new();
+    for key in [3, 1, 3, 2, 3, 1] {
+        map.modify_or_insert(key, || 0, |v| *v += 1);
+    }
+    assert_map_same(&map, [(1, vec![2, 1, 3])]);
+}
@@ -0,0 +1,47 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.nth_region(0));
+}
+
+#[test]
+fn first() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    assert_eq!((1, &[11, 12][..]), map.nth_region(0).unwrap());
+}
+
+#[test]
+fn middle() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    assert_eq!((5, &[15, 16, 17][..]), map.nth_region(1).unwrap());
+}
+
+#[test]
+fn last() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    assert_eq!((10, &[20][..]), map.nth_region(2).unwrap());
+}
+
+#[test]
+fn out_of_bounds() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    assert_eq!(None, map.nth_region(3));
+}
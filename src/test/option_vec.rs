@@ -0,0 +1,54 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn to_option_vec_empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(vec![None, None], map.to_option_vec(0, 2));
+}
+
+#[test]
+fn to_option_vec_gaps() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+        10 => 20;
+    );
+    assert_eq!(
+        vec![Some(12), None, None, Some(15), None],
+        map.to_option_vec(2, 5)
+    );
+}
+
+#[test]
+fn to_option_vec_near_overflow() {
+    let map = cmap!(u8::MAX - 1 => 1, 2);
+    assert_eq!(
+        vec![Some(1), Some(2), None],
+        map.to_option_vec(u8::MAX - 1, 3)
+    );
+}
+
+#[test]
+fn from_option_vec_gaps() {
+    let map = ContiguousMap::from_option_vec(1, vec![Some(11), Some(12), None, Some(14)]);
+    assert_map_same(&map, [(1, vec![11, 12]), (4, vec![14])]);
+}
+
+#[test]
+fn from_option_vec_near_overflow() {
+    let map = ContiguousMap::from_option_vec(u8::MAX, vec![Some(1), Some(2)]);
+    assert_eq!(1, map.len());
+    assert_eq!(Some(&1), map.get(u8::MAX));
+}
+
+#[test]
+fn round_trip() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+        10 => 20;
+    );
+    let vec = map.to_option_vec(0, 11);
+    assert_eq!(map, ContiguousMap::from_option_vec(0, vec));
+}
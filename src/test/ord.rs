@@ -0,0 +1,90 @@
+use crate::{cmap, ContiguousMap};
+use std::cmp::Ordering;
+
+#[test]
+fn equal() {
+    let map1 = cmap!(
+        10 => 1, 2, 3;
+        20 => 5;
+    );
+    let map2 = cmap!(
+        10 => 1, 2, 3;
+        20 => 5;
+    );
+    assert_eq!(Ordering::Equal, map1.cmp(&map2));
+}
+
+#[test]
+fn empty() {
+    let map1 = ContiguousMap::new();
+    let map2 = cmap!(10 => 1);
+    assert!(map1 < map2);
+    assert_eq!(Ordering::Equal, map1.cmp(&ContiguousMap::new()));
+}
+
+#[test]
+fn value_different() {
+    let map1 = cmap!(10 => 1, 2, 3);
+    let map2 = cmap!(10 => 1, 2, 5);
+    assert!(map1 < map2);
+    assert!(map2 > map1);
+}
+
+#[test]
+fn prefix_is_less() {
+    let map1 = cmap!(10 => 1, 2);
+    let map2 = cmap!(10 => 1, 2, 3);
+    assert!(map1 < map2);
+
+    let map3 = cmap!(
+        10 => 1, 2;
+        20 => 0;
+    );
+    assert!(map1 < map3);
+}
+
+#[test]
+fn start_key_different() {
+    // keys are compared before values
+    let map1 = cmap!(10 => 5, 6);
+    let map2 = cmap!(11 => 1, 2);
+    assert!(map1 < map2);
+}
+
+#[test]
+fn gap_position() {
+    let map1 = cmap!(10 => 1; 12 => 3);
+    let map2 = cmap!(10 => 1; 11 => 2);
+    assert!(map1 > map2);
+}
+
+#[test]
+fn sort() {
+    let mut maps = vec![
+        cmap!(10 => 1, 2, 3),
+        cmap!(10 => 1),
+        cmap!(5 => 9),
+        cmap!(10 => 1, 2),
+    ];
+    maps.sort();
+    assert_eq!(
+        vec![
+            cmap!(5 => 9),
+            cmap!(10 => 1),
+            cmap!(10 => 1, 2),
+            cmap!(10 => 1, 2, 3),
+        ],
+        maps
+    );
+}
+
+#[test]
+fn partial_cmp_floats() {
+    let map1 = cmap!(10 => 1.0);
+    let map2 = cmap!(10 => f64::NAN);
+    assert_eq!(None, map1.partial_cmp(&map2));
+    assert_eq!(
+        Some(Ordering::Less),
+        cmap!(10 => 1.0).partial_cmp(&cmap!(10 => 2.0))
+    );
+}
@@ -50,3 +50,55 @@ fn contains_nan() {
     let map2 = cmap!(10 => 1.0, f64::NAN, 3.0);
     assert_ne!(map1, map2);
 }
+
+mod btree_map {
+    use crate::cmap;
+    use std::collections::BTreeMap;
+
+    fn btree_map(entries: &[(usize, i32)]) -> BTreeMap<usize, i32> {
+        entries.iter().cloned().collect()
+    }
+
+    #[test]
+    fn equal() {
+        let map = cmap!(1 => 1, 2, 3);
+        assert_eq!(map, btree_map(&[(1, 1), (2, 2), (3, 3)]));
+    }
+
+    #[test]
+    fn equal_with_gap() {
+        let map = cmap!(1 => 1; 3 => 3);
+        assert_eq!(map, btree_map(&[(1, 1), (3, 3)]));
+    }
+
+    #[test]
+    fn empty() {
+        let map = crate::ContiguousMap::new();
+        assert_eq!(map, btree_map(&[]));
+        assert_ne!(cmap!(1 => 1), btree_map(&[]));
+    }
+
+    #[test]
+    fn missing_key() {
+        let map = cmap!(1 => 1, 2, 3);
+        assert_ne!(map, btree_map(&[(1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn extra_key() {
+        let map = cmap!(1 => 1, 2);
+        assert_ne!(map, btree_map(&[(1, 1), (2, 2), (3, 3)]));
+    }
+
+    #[test]
+    fn value_different() {
+        let map = cmap!(1 => 1, 2, 3);
+        assert_ne!(map, btree_map(&[(1, 1), (2, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn key_different() {
+        let map = cmap!(1 => 1, 2, 3);
+        assert_ne!(map, btree_map(&[(1, 1), (2, 2), (4, 3)]));
+    }
+}
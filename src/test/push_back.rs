@@ -0,0 +1,67 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap, PushError};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Ok(()), map.push_back(5, 15));
+    assert_map_same(&map, [(5, vec![15])]);
+    assert_eq!(1, map.len());
+}
+
+#[test]
+fn appends_to_last_region() {
+    let mut map = cmap!(
+        1 => 11;
+        5 => 15, 16;
+    );
+    assert_eq!(Ok(()), map.push_back(7, 17));
+    assert_eq!(Ok(()), map.push_back(8, 18));
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15, 16, 17, 18])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn starts_new_region() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(Ok(()), map.push_back(5, 15));
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn not_increasing() {
+    let mut map = cmap!(
+        1 => 11;
+        5 => 15, 16;
+    );
+    assert_eq!(Err(PushError(20)), map.push_back(6, 20));
+    assert_eq!(Err(PushError(20)), map.push_back(5, 20));
+    assert_eq!(Err(PushError(20)), map.push_back(3, 20));
+    assert_eq!(Err(PushError(20)), map.push_back(0, 20));
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15, 16])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn matches_insert() {
+    let keys: [usize; 10] = [1, 2, 3, 7, 8, 20, 21, 22, 23, 30];
+    let mut pushed = ContiguousMap::new();
+    let mut inserted = ContiguousMap::new();
+    for key in keys {
+        pushed.push_back(key, key as i32).unwrap();
+        inserted.insert(key, key as i32);
+    }
+    assert!(pushed == inserted);
+    assert_eq!(inserted.len(), pushed.len());
+}
+
+#[test]
+fn difference_exceeds_usize() {
+    let mut map = cmap!(0u128 => 1);
+    assert!(map.push_back(u128::MAX, 2).is_ok());
+    assert_eq!(Some(&1), map.get(0));
+    assert_eq!(Some(&2), map.get(u128::MAX));
+    assert_eq!(2, map.num_contiguous_regions());
+    assert_eq!(2, map.len());
+}
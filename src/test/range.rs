@@ -56,3 +56,38 @@ fn reverse() {
     assert_eq!((1, &11), range.next_back().unwrap());
     assert_de_iter_empty(range);
 }
+
+#[test]
+fn excluded_start() {
+    use std::ops::Bound;
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    let mut range = map.range((Bound::Excluded(1), Bound::Included(5)));
+    assert_eq!((2, &12), range.next().unwrap());
+    assert_eq!((5, &15), range.next().unwrap());
+    assert_de_iter_empty(range);
+}
+
+#[test]
+fn large_offset() {
+    // offsets from the start of a region to the range bounds that do not fit in a usize
+    let map = cmap!(
+        0u128 => 1, 2;
+        u128::MAX => 3;
+    );
+    let mut range = map.range(..u128::MAX);
+    assert_eq!((0, &1), range.next().unwrap());
+    assert_eq!((1, &2), range.next().unwrap());
+    assert_de_iter_empty(range);
+
+    let mut range = map.range(1..=u128::MAX - 1);
+    assert_eq!((1, &2), range.next().unwrap());
+    assert_de_iter_empty(range);
+
+    let mut range = map.range(1..);
+    assert_eq!((u128::MAX, &3), range.next_back().unwrap());
+    assert_eq!((1, &2), range.next_back().unwrap());
+    assert_de_iter_empty(range);
+}
@@ -1,4 +1,4 @@
-use super::assert_de_iter_empty;
+use super::{assert_de_iter_empty, assert_map_same};
 use crate::cmap;
 
 #[test]
@@ -56,3 +56,15 @@ fn reverse() {
     assert_eq!((1, &mut 11), range.next_back().unwrap());
     assert_de_iter_empty(range);
 }
+
+#[test]
+fn modify_values() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    for (_, value) in map.range_mut(2..=6) {
+        *value += 100;
+    }
+    assert_map_same(&map, [(1, vec![11, 112]), (5, vec![115, 116, 17])]);
+}
@@ -0,0 +1,51 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(0, map.rank(5));
+}
+
+#[test]
+fn before_all_regions() {
+    let map = cmap!(
+        3 => 13, 14;
+        7 => 17;
+    );
+    assert_eq!(0, map.rank(0));
+    assert_eq!(0, map.rank(3));
+}
+
+#[test]
+fn inside_region() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        7 => 17, 18, 19;
+    );
+    assert_eq!(1, map.rank(2));
+    assert_eq!(2, map.rank(3));
+    assert_eq!(4, map.rank(8));
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        7 => 17, 18;
+    );
+    assert_eq!(3, map.rank(4));
+    assert_eq!(3, map.rank(7));
+    assert_eq!(5, map.rank(100));
+}
+
+#[test]
+fn inverse_of_get_nth() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    for (key, value) in map.iter() {
+        assert_eq!(Some((key, value)), map.get_nth(map.rank(key)));
+    }
+}
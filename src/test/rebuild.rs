@@ -0,0 +1,34 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    map.rebuild();
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn unchanged() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    map.rebuild();
+    assert_map_same(
+        &map,
+        [(1, vec![11, 12]), (5, vec![15, 16, 17]), (10, vec![20])],
+    );
+}
+
+#[test]
+fn after_churn() {
+    let mut map = ContiguousMap::new();
+    for key in 0..1000 {
+        map.insert(key * 2, key as i32);
+    }
+    map.clear_range(2..1998);
+    map.rebuild();
+    assert_map_same(&map, [(0, vec![0]), (1998, vec![999])]);
+}
@@ -0,0 +1,36 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.region_capacity(1));
+}
+
+#[test]
+fn at_least_region_length() {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(1, &[11, 12, 13, 14]);
+    for key in 1..5 {
+        assert!(map.region_capacity(key).unwrap() >= 4);
+    }
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(None, map.region_capacity(0));
+    assert_eq!(None, map.region_capacity(3));
+    assert_eq!(None, map.region_capacity(6));
+}
+
+#[test]
+fn spare_capacity() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    let capacity = map.region_capacity(1).unwrap();
+    map.region_pop_back(1);
+    map.region_pop_back(1);
+    assert_eq!(Some(capacity), map.region_capacity(2));
+}
@@ -0,0 +1,44 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.region_chunks(2).next());
+}
+
+#[test]
+fn partial_last_chunk() {
+    let map = cmap!(3 => 13, 14, 15, 16, 17);
+    let chunks: Vec<_> = map.region_chunks(2).collect();
+    assert_eq!(
+        vec![(3, &[13, 14][..]), (5, &[15, 16][..]), (7, &[17][..])],
+        chunks
+    );
+}
+
+#[test]
+fn gaps_break_chunks() {
+    let map = cmap!(
+        0 => 0, 1, 2;
+        4 => 4, 5;
+    );
+    let chunks: Vec<_> = map.region_chunks(2).collect();
+    assert_eq!(
+        vec![(0, &[0, 1][..]), (2, &[2][..]), (4, &[4, 5][..])],
+        chunks
+    );
+}
+
+#[test]
+fn chunk_keys_near_max() {
+    let map = cmap!(251u8 => 1, 2, 3, 4, 5);
+    let keys: Vec<u8> = map.region_chunks(2).map(|(key, _)| key).collect();
+    assert_eq!(vec![251, 253, 255], keys);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn zero_size() {
+    let map = cmap!(0 => 0);
+    let _ = map.region_chunks(0);
+}
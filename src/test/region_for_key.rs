@@ -0,0 +1,46 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.region_for_key(1));
+}
+
+#[test]
+fn region_start() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some(1), map.region_for_key(1));
+    assert_eq!(Some(5), map.region_for_key(5));
+}
+
+#[test]
+fn interior() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some(1), map.region_for_key(2));
+    assert_eq!(Some(1), map.region_for_key(3));
+}
+
+#[test]
+fn in_gap() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(None, map.region_for_key(4));
+}
+
+#[test]
+fn out_of_range() {
+    let map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(None, map.region_for_key(0));
+    assert_eq!(None, map.region_for_key(6));
+}
@@ -0,0 +1,46 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.region_pop_back(1));
+}
+
+#[test]
+fn in_gap() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(None, map.region_pop_back(3));
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn shrinks_region() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((3, 13)), map.region_pop_back(1));
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn drops_empty_region() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((5, 15)), map.region_pop_back(5));
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+}
+
+#[test]
+fn near_overflow() {
+    let mut map = cmap!(u8::MAX - 1 => 1, 2);
+    assert_eq!(Some((u8::MAX, 2)), map.region_pop_back(u8::MAX));
+    assert_eq!(Some((u8::MAX - 1, 1)), map.region_pop_back(u8::MAX - 1));
+    assert!(map.is_empty());
+}
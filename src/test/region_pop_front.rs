@@ -0,0 +1,38 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.region_pop_front(1));
+}
+
+#[test]
+fn in_gap() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(None, map.region_pop_front(3));
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn shifts_start_key() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((1, 11)), map.region_pop_front(2));
+    assert_map_same(&map, [(2, vec![12, 13]), (5, vec![15])]);
+}
+
+#[test]
+fn drops_empty_region() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        5 => 15;
+    );
+    assert_eq!(Some((5, 15)), map.region_pop_front(5));
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+}
@@ -0,0 +1,48 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.region_windows(2).next());
+}
+
+#[test]
+fn single_region() {
+    let map = cmap!(0 => 0, 1, 2, 3);
+    let windows: Vec<_> = map.region_windows(2).collect();
+    assert_eq!(
+        vec![(0, &[0, 1][..]), (1, &[1, 2][..]), (2, &[2, 3][..])],
+        windows
+    );
+}
+
+#[test]
+fn gaps_break_windows() {
+    let map = cmap!(
+        0 => 0, 1, 2;
+        4 => 4, 5;
+    );
+    let windows: Vec<_> = map.region_windows(2).collect();
+    assert_eq!(
+        vec![(0, &[0, 1][..]), (1, &[1, 2][..]), (4, &[4, 5][..])],
+        windows
+    );
+}
+
+#[test]
+fn skips_short_regions() {
+    let map = cmap!(
+        0 => 0, 1;
+        4 => 4, 5, 6;
+        9 => 9;
+    );
+    let windows: Vec<_> = map.region_windows(3).collect();
+    assert_eq!(vec![(4, &[4, 5, 6][..])], windows);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn zero_size() {
+    let map = cmap!(0 => 0);
+    let _ = map.region_windows(0);
+}
@@ -0,0 +1,32 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, usize>::new();
+    assert_eq!(None, map.regions().next());
+}
+
+#[test]
+fn owned_keys_match_region_starts() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    let regions: Vec<(usize, &[i32])> = map.regions().collect();
+    assert_eq!(
+        vec![(1, &[11, 12][..]), (5, &[15, 16, 17][..]), (10, &[20][..])],
+        regions
+    );
+}
+
+#[test]
+fn matches_iter_slice() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert!(map
+        .regions()
+        .eq(map.iter_slice().map(|(key, slice)| (*key, slice))));
+}
@@ -0,0 +1,79 @@
+use super::assert_map_same;
+use crate::cmap;
+
+#[test]
+fn empty_range() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(Vec::<(usize, i32)>::new(), map.remove_range(5..10));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn entire_region() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(vec![(1, 11), (2, 12)], map.remove_range(..5));
+    assert_map_same(&map, [(5, vec![15])]);
+}
+
+#[test]
+fn middle_of_region() {
+    let mut map = cmap!(1 => 11, 12, 13, 14);
+    assert_eq!(vec![(2, 12), (3, 13)], map.remove_range(2..=3));
+    assert_map_same(&map, [(1, vec![11]), (4, vec![14])]);
+}
+
+#[test]
+fn front_of_region() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(vec![(1, 11)], map.remove_range(0..2));
+    assert_map_same(&map, [(2, vec![12, 13])]);
+}
+
+#[test]
+fn back_of_region() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(vec![(3, 13)], map.remove_range(3..));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn across_two_regions() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16, 17, 18;
+    );
+    assert_eq!(
+        vec![(2, 12), (3, 13), (6, 16), (7, 17)],
+        map.remove_range(2..8)
+    );
+    assert_map_same(&map, [(1, vec![11]), (8, vec![18])]);
+}
+
+#[test]
+fn across_many_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14;
+        6 => 16;
+        8 => 18, 19;
+    );
+    assert_eq!(
+        vec![(2, 12), (4, 14), (6, 16), (8, 18), (9, 19)],
+        map.remove_range(2..)
+    );
+    assert_map_same(&map, [(1, vec![11])]);
+}
+
+#[test]
+fn at_max_key() {
+    let mut map = crate::ContiguousMap::new();
+    map.insert_slice(u8::MAX - 2, &[1, 2, 3]);
+    assert_eq!(
+        vec![(u8::MAX - 1, 2), (u8::MAX, 3)],
+        map.remove_range(u8::MAX - 1..)
+    );
+    assert_eq!(1, map.len());
+}
@@ -0,0 +1,55 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.remove_region(1));
+}
+
+#[test]
+fn start_key() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        7 => 17;
+    );
+    assert_eq!(Some((1, vec![11, 12, 13])), map.remove_region(1));
+    assert_map_same(&map, [(7, vec![17])]);
+    assert_eq!(1, map.len());
+}
+
+#[test]
+fn middle_key() {
+    let mut map = cmap!(
+        1 => 11;
+        4 => 14, 15, 16;
+        9 => 19, 20;
+    );
+    assert_eq!(Some((4, vec![14, 15, 16])), map.remove_region(5));
+    assert_map_same(&map, [(1, vec![11]), (9, vec![19, 20])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn last_key() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        9 => 19, 20;
+    );
+    assert_eq!(Some((9, vec![19, 20])), map.remove_region(10));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn key_in_gap() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        9 => 19;
+    );
+    assert_eq!(None, map.remove_region(0));
+    assert_eq!(None, map.remove_region(3));
+    assert_eq!(None, map.remove_region(10));
+    assert_map_same(&map, [(1, vec![11, 12]), (9, vec![19])]);
+    assert_eq!(3, map.len());
+}
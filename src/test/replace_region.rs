@@ -0,0 +1,79 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty_map() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(
+        Vec::<(usize, i32)>::new(),
+        map.replace_region(1, vec![11, 12])
+    );
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn empty_values() {
+    let mut map = cmap!(1 => 11, 12);
+    assert_eq!(Vec::<(usize, i32)>::new(), map.replace_region(1, vec![]));
+    assert_map_same(&map, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn middle_of_region() {
+    let mut map = cmap!(1 => 11, 12, 13, 14, 15);
+    assert_eq!(vec![(2, 12), (3, 13)], map.replace_region(2, vec![20, 30]));
+    assert_map_same(&map, [(1, vec![11, 20, 30, 14, 15])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn spans_gap() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16;
+    );
+    assert_eq!(
+        vec![(2, 12), (5, 15)],
+        map.replace_region(2, vec![20, 30, 40, 50])
+    );
+    assert_map_same(&map, [(1, vec![11, 20, 30, 40, 50, 16])]);
+    assert_eq!(6, map.len());
+}
+
+#[test]
+fn merges_with_adjacent_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(
+        Vec::<(usize, i32)>::new(),
+        map.replace_region(3, vec![13, 14])
+    );
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14, 15])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn separate_from_other_regions() {
+    let mut map = cmap!(
+        1 => 11;
+        10 => 20;
+    );
+    assert_eq!(
+        Vec::<(usize, i32)>::new(),
+        map.replace_region(4, vec![14, 15])
+    );
+    assert_map_same(&map, [(1, vec![11]), (4, vec![14, 15]), (10, vec![20])]);
+    assert_eq!(4, map.len());
+}
+
+#[test]
+fn overflow() {
+    let mut map = cmap!(254u8 => 1);
+    assert_eq!(vec![(254, 1)], map.replace_region(254u8, vec![2, 3, 4]));
+    assert_eq!(Some(&[2, 3][..]), map.get_slice(254u8..));
+    assert_eq!(1, map.num_contiguous_regions());
+    assert_eq!(2, map.len());
+}
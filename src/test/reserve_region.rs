@@ -0,0 +1,43 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    map.reserve_region(1, 10);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn within_region() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    map.reserve_region(2, 10);
+    assert!(map.region_capacity(1).unwrap() >= 12);
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn after_region_end() {
+    let mut map = cmap!(1 => 11, 12);
+    map.reserve_region(3, 10);
+    assert!(map.region_capacity(1).unwrap() >= 12);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn in_gap() {
+    let mut map = cmap!(
+        1 => 11;
+        5 => 15;
+    );
+    let capacities = (map.region_capacity(1), map.region_capacity(5));
+    map.reserve_region(3, 10);
+    map.reserve_region(0, 10);
+    assert_eq!(capacities, (map.region_capacity(1), map.region_capacity(5)));
+    assert_map_same(&map, [(1, vec![11]), (5, vec![15])]);
+}
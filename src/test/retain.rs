@@ -0,0 +1,52 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    map.retain(|_, _| false);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn keep_all() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    map.retain(|_, _| true);
+    assert_map_same(&map, [(1, vec![11, 12]), (5, vec![15, 16, 17])]);
+}
+
+#[test]
+fn remove_all() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    map.retain(|_, _| false);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn splits_regions() {
+    let mut map = cmap!(
+        1 => 11, 12, 13, 14, 15;
+        10 => 20, 21;
+    );
+    map.retain(|key, _| key % 3 != 0);
+    assert_map_same(
+        &map,
+        [(1, vec![11, 12]), (4, vec![14, 15]), (10, vec![20, 21])],
+    );
+}
+
+#[test]
+fn mutates_values() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    map.retain(|_, value| {
+        *value += 100;
+        *value != 112
+    });
+    assert_map_same(&map, [(1, vec![111]), (3, vec![113])]);
+}
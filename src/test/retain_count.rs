@@ -0,0 +1,89 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    assert_eq!(0, map.retain_count(|_, _| false));
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn keep_all() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(0, map.retain_count(|_, _| true));
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn fragmented() {
+    let mut map = cmap!(
+        1 => 11, 12, 13, 14, 15;
+        10 => 20, 21, 22;
+        20 => 30;
+    );
+    let mut rejected = 0;
+    let removed = map.retain_count(|_, value| {
+        let keep = *value % 2 == 0;
+        if !keep {
+            rejected += 1;
+        }
+        keep
+    });
+    assert_eq!(rejected, removed);
+    assert_eq!(4, removed);
+    assert_eq!(5, map.len());
+    assert_map_same(
+        &map,
+        [
+            (2, vec![12]),
+            (4, vec![14]),
+            (10, vec![20]),
+            (12, vec![22]),
+            (20, vec![30]),
+        ],
+    );
+}
+
+#[test]
+fn predicate_panic_keeps_length() {
+    let mut map = cmap!(
+        0 => 10, 11, 12;
+        5 => 15, 16, 17;
+        9 => 19;
+    );
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.retain_count(|key, _| {
+            assert_ne!(6, key);
+            key != 1
+        })
+    }));
+    assert!(result.is_err());
+    assert_map_same(
+        &map,
+        [
+            (0, vec![10]),
+            (2, vec![12]),
+            (5, vec![15, 16, 17]),
+            (9, vec![19]),
+        ],
+    );
+    assert_eq!(6, map.len());
+}
+
+#[test]
+fn predicate_panic_after_rejection() {
+    let mut map = cmap!(0 => 10, 11, 12, 13);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.retain_count(|key, _| {
+            assert_ne!(2, key);
+            key != 1
+        })
+    }));
+    assert!(result.is_err());
+    assert_map_same(&map, [(0, vec![10]), (2, vec![12, 13])]);
+    assert_eq!(3, map.len());
+}
@@ -0,0 +1,134 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map: ContiguousMap<usize, i32> = ContiguousMap::new();
+    map.retain_regions(|_, _| false);
+    assert_map_same(&map, []);
+}
+
+#[test]
+fn remove_short_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14;
+        6 => 16, 17, 18;
+        10 => 20;
+    );
+    map.retain_regions(|_, vec| vec.len() >= 2);
+    assert_map_same(&map, [(1, vec![11, 12]), (6, vec![16, 17, 18])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn given_start_keys() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        6 => 16;
+    );
+    let mut keys = Vec::new();
+    map.retain_regions(|key, _| {
+        keys.push(key);
+        true
+    });
+    assert_eq!(vec![1, 6], keys);
+}
+
+#[test]
+fn edit_values() {
+    let mut map = cmap!(
+        1 => 11, 12, 13;
+        6 => 16;
+    );
+    map.retain_regions(|key, vec| {
+        if key == 1 {
+            vec.truncate(1);
+        } else {
+            vec[0] += 100;
+        }
+        true
+    });
+    assert_map_same(&map, [(1, vec![11]), (6, vec![116])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn emptied_region_removed() {
+    let mut map = cmap!(1 => 11, 12; 6 => 16);
+    map.retain_regions(|key, vec| {
+        if key == 6 {
+            vec.clear();
+        }
+        true
+    });
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_eq!(2, map.len());
+}
+
+#[test]
+fn extended_region_merges() {
+    let mut map = cmap!(1 => 11, 12; 4 => 14);
+    map.retain_regions(|key, vec| {
+        if key == 1 {
+            vec.push(13);
+        }
+        true
+    });
+    assert_map_same(&map, [(1, vec![11, 12, 13, 14])]);
+    assert_eq!(4, map.len());
+}
+
+#[test]
+#[should_panic(expected = "into the next region")]
+fn extended_region_overlaps() {
+    let mut map = cmap!(1 => 11, 12; 4 => 14);
+    map.retain_regions(|key, vec| {
+        if key == 1 {
+            vec.extend([13, 14]);
+        }
+        true
+    });
+}
+
+#[test]
+fn overlap_panic_leaves_valid_map() {
+    let mut map = cmap!(
+        1 => 11;
+        3 => 13, 14;
+        6 => 16;
+        9 => 19;
+    );
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.retain_regions(|key, vec| {
+            if key == 3 {
+                vec.extend([15, 16]);
+            }
+            true
+        })
+    }));
+    assert!(result.is_err());
+    assert_map_same(&map, [(1, vec![11]), (6, vec![16]), (9, vec![19])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn predicate_panic_leaves_valid_map() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14;
+        6 => 16;
+    );
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.retain_regions(|key, vec| {
+            if key == 1 {
+                vec.push(13);
+            }
+            assert_ne!(4, key);
+            true
+        })
+    }));
+    assert!(result.is_err());
+    assert_map_same(&map, [(1, vec![11, 12, 13]), (6, vec![16])]);
+    assert_eq!(4, map.len());
+}
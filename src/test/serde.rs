@@ -0,0 +1,92 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap, ToIndex, TryFromIndex};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn serialize_empty() {
+    let map = ContiguousMap::<u32, i32>::new();
+    assert_eq!("[]", serde_json::to_string(&map).unwrap());
+}
+
+#[test]
+fn serialize_regions() {
+    let map = cmap!(
+        1u32 => 11, 12;
+        5 => 15;
+    );
+    assert_eq!(
+        "[[1,[11,12]],[5,[15]]]",
+        serde_json::to_string(&map).unwrap()
+    );
+}
+
+#[test]
+fn round_trip_u32() {
+    let map = cmap!(
+        1u32 => 11, 12;
+        5 => 15, 16, 17;
+        u32::MAX => 20;
+    );
+    let json = serde_json::to_string(&map).unwrap();
+    let deserialized: ContiguousMap<u32, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(map, deserialized);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+struct Even(u8);
+
+impl ToIndex for Even {
+    type Index = u8;
+    fn to_index(&self) -> Self::Index {
+        self.0 / 2
+    }
+}
+
+impl TryFromIndex for Even {
+    fn try_from_index(index: Self::Index) -> Option<Self> {
+        Some(Self(index.checked_mul(2)?))
+    }
+}
+
+#[test]
+fn round_trip_custom_key() {
+    let map = cmap!(
+        Even(2) => 1, 2;
+        Even(10) => 3;
+    );
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!("[[2,[1,2]],[10,[3]]]", json);
+    let deserialized: ContiguousMap<Even, i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(map, deserialized);
+}
+
+#[test]
+fn deserialize_merges_adjacent() {
+    let map: ContiguousMap<usize, i32> = serde_json::from_str("[[1,[11,12]],[3,[13]]]").unwrap();
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+}
+
+#[test]
+fn deserialize_empty_region() {
+    let result: Result<ContiguousMap<usize, i32>, _> = serde_json::from_str("[[1,[11]],[5,[]]]");
+    assert!(result.is_err());
+}
+
+#[test]
+fn deserialize_unsorted() {
+    let result: Result<ContiguousMap<usize, i32>, _> = serde_json::from_str("[[5,[15]],[1,[11]]]");
+    assert!(result.is_err());
+}
+
+#[test]
+fn deserialize_overlapping() {
+    let result: Result<ContiguousMap<usize, i32>, _> =
+        serde_json::from_str("[[1,[11,12]],[2,[22]]]");
+    assert!(result.is_err());
+}
+
+#[test]
+fn deserialize_overflow() {
+    let result: Result<ContiguousMap<u8, i32>, _> = serde_json::from_str("[[255,[1,2]]]");
+    assert!(result.is_err());
+}
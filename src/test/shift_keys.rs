@@ -0,0 +1,45 @@
+use crate::{test_util::assert_regions, ContiguousMap, ShiftError};
+
+fn make_map() -> ContiguousMap<u8, i32> {
+    let mut map = ContiguousMap::new();
+    map.insert_slice(1, &[11, 12]);
+    map.insert_slice(5, &[15]);
+    map
+}
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<u8, i32>::new();
+    assert_eq!(Ok(()), map.shift_keys(1000));
+    assert!(map.is_empty());
+}
+
+#[test]
+fn zero() {
+    let mut map = make_map();
+    assert_eq!(Ok(()), map.shift_keys(0));
+    assert_regions(&map, &[(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn two_regions() {
+    let mut map = make_map();
+    assert_eq!(Ok(()), map.shift_keys(3));
+    assert_regions(&map, &[(4, vec![11, 12]), (8, vec![15])]);
+}
+
+#[test]
+fn to_max() {
+    let mut map = make_map();
+    assert_eq!(Ok(()), map.shift_keys(250));
+    assert_regions(&map, &[(251, vec![11, 12]), (255, vec![15])]);
+}
+
+#[test]
+fn overflow() {
+    let mut map = make_map();
+    assert_eq!(Err(ShiftError), map.shift_keys(251));
+    assert_regions(&map, &[(1, vec![11, 12]), (5, vec![15])]);
+    assert_eq!(Err(ShiftError), map.shift_keys(usize::MAX));
+    assert_regions(&map, &[(1, vec![11, 12]), (5, vec![15])]);
+}
@@ -0,0 +1,71 @@
+use super::assert_map_same;
+use crate::{ContiguousMap, ShrinkPolicy};
+
+/// Makes a map with a single region of 100 values starting at a key of 0.
+fn make_map(policy: ShrinkPolicy) -> ContiguousMap<usize, i32> {
+    let values: Vec<i32> = (0..100).collect();
+    let mut map = ContiguousMap::new();
+    map.set_shrink_policy(policy);
+    map.insert_slice(0, &values);
+    map
+}
+
+#[test]
+fn default_never() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(ShrinkPolicy::Never, map.shrink_policy());
+}
+
+#[test]
+fn never_remove() {
+    let mut map = make_map(ShrinkPolicy::Never);
+    let capacity = map.map[&0].capacity();
+    for key in (10..100).rev() {
+        map.remove(key);
+    }
+    assert_eq!(capacity, map.map[&0].capacity());
+    assert_map_same(&map, [(0, (0..10).collect())]);
+}
+
+#[test]
+fn never_clear_range() {
+    let mut map = make_map(ShrinkPolicy::Never);
+    let capacity = map.map[&0].capacity();
+    map.clear_range(10..);
+    assert_eq!(capacity, map.map[&0].capacity());
+    assert_map_same(&map, [(0, (0..10).collect())]);
+}
+
+#[test]
+fn on_ratio_remove() {
+    let mut map = make_map(ShrinkPolicy::OnRatio(0.5));
+    for key in (10..100).rev() {
+        map.remove(key);
+    }
+    assert!(map.map[&0].capacity() < 20);
+    assert_map_same(&map, [(0, (0..10).collect())]);
+}
+
+#[test]
+fn on_ratio_remove_splits() {
+    let mut map = make_map(ShrinkPolicy::OnRatio(0.5));
+    map.remove(10);
+    assert_eq!(10, map.map[&0].capacity());
+    assert_map_same(&map, [(0, (0..10).collect()), (11, (11..100).collect())]);
+}
+
+#[test]
+fn on_ratio_clear_range() {
+    let mut map = make_map(ShrinkPolicy::OnRatio(0.5));
+    map.clear_range(10..);
+    assert_eq!(10, map.map[&0].capacity());
+    assert_map_same(&map, [(0, (0..10).collect())]);
+}
+
+#[test]
+fn on_ratio_above_ratio() {
+    let mut map = make_map(ShrinkPolicy::OnRatio(0.5));
+    let capacity = map.map[&0].capacity();
+    map.clear_range(90..);
+    assert_eq!(capacity, map.map[&0].capacity());
+}
@@ -0,0 +1,32 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(None, map.slices().into_iter().next());
+}
+
+#[test]
+fn for_loop() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+        10 => 20;
+    );
+    let mut regions = Vec::new();
+    for (key, slice) in map.slices() {
+        regions.push((*key, slice));
+    }
+    assert_eq!(
+        vec![(1, &[11, 12][..]), (5, &[15, 16, 17][..]), (10, &[20][..])],
+        regions
+    );
+}
+
+#[test]
+fn reusable() {
+    let map = cmap!(1 => 11, 12; 5 => 15);
+    let slices = map.slices();
+    assert_eq!(2, slices.into_iter().count());
+    assert!(slices.into_iter().eq(map.iter_slice()));
+}
@@ -0,0 +1,59 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    let tail = map.split_off(5);
+    assert_map_same(&map, []);
+    assert_map_same(&tail, []);
+}
+
+#[test]
+fn inside_region() {
+    let mut map = cmap!(
+        1 => 11, 12, 13, 14;
+        10 => 20;
+    );
+    let tail = map.split_off(3);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_map_same(&tail, [(3, vec![13, 14]), (10, vec![20])]);
+}
+
+#[test]
+fn region_boundary() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        10 => 20, 21;
+    );
+    let tail = map.split_off(10);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_map_same(&tail, [(10, vec![20, 21])]);
+}
+
+#[test]
+fn in_gap() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        10 => 20, 21;
+    );
+    let tail = map.split_off(5);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_map_same(&tail, [(10, vec![20, 21])]);
+}
+
+#[test]
+fn before_first_region() {
+    let mut map = cmap!(1 => 11, 12);
+    let tail = map.split_off(0);
+    assert_map_same(&map, []);
+    assert_map_same(&tail, [(1, vec![11, 12])]);
+}
+
+#[test]
+fn after_last_region() {
+    let mut map = cmap!(1 => 11, 12);
+    let tail = map.split_off(3);
+    assert_map_same(&map, [(1, vec![11, 12])]);
+    assert_map_same(&tail, []);
+}
@@ -0,0 +1,22 @@
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert!(map.to_range_set().is_empty());
+}
+
+#[test]
+fn two_regions() {
+    let map = cmap!(
+        0 => 'a', 'b', 'c';
+        10 => 'd';
+    );
+    assert_eq!(vec![0..=2, 10..=10], map.to_range_set());
+}
+
+#[test]
+fn near_overflow() {
+    let map = cmap!(u8::MAX - 1 => 1, 2);
+    assert_eq!(vec![u8::MAX - 1..=u8::MAX], map.to_range_set());
+}
@@ -0,0 +1,53 @@
+use super::assert_map_same;
+use crate::{cmap, ContiguousMap, OccupiedError};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::new();
+    assert_eq!(Ok(&mut 2), map.try_insert(1, 2));
+    assert_map_same(&map, [(1, vec![2])]);
+    assert_eq!(1, map.len());
+}
+
+#[test]
+fn vacant_returns_inserted_value() {
+    let mut map = cmap!(1 => 11);
+    let value = map.try_insert(5, 15).unwrap();
+    *value += 100;
+    assert_map_same(&map, [(1, vec![11]), (5, vec![115])]);
+}
+
+#[test]
+fn vacant_merges_regions() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        4 => 14, 15;
+    );
+    let value = map.try_insert(3, 13).unwrap();
+    assert_eq!(13, *value);
+    *value = 30;
+    assert_map_same(&map, [(1, vec![11, 12, 30, 14, 15])]);
+    assert_eq!(5, map.len());
+}
+
+#[test]
+fn occupied() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    assert_eq!(
+        Err(OccupiedError {
+            existing: &mut 12,
+            value: 20,
+        }),
+        map.try_insert(2, 20)
+    );
+    assert_map_same(&map, [(1, vec![11, 12, 13])]);
+    assert_eq!(3, map.len());
+}
+
+#[test]
+fn occupied_existing_is_mutable() {
+    let mut map = cmap!(1 => 11, 12, 13);
+    let error = map.try_insert(3, 20).unwrap_err();
+    *error.existing = error.value;
+    assert_map_same(&map, [(1, vec![11, 12, 20])]);
+}
@@ -0,0 +1,39 @@
+use crate::{cmap, BoundedKey, ContiguousMap};
+
+crate::bounded_key!(Tiny, u8, 0..=15);
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_eq!(Some(0), map.try_len());
+}
+
+#[test]
+fn matches_len() {
+    let mut map = cmap!(
+        1 => 11, 12;
+        5 => 15, 16, 17;
+    );
+    assert_eq!(Some(map.len()), map.try_len());
+    map.remove(6);
+    assert_eq!(Some(4), map.try_len());
+    assert_eq!(Some(map.len()), map.try_len());
+}
+
+#[test]
+fn entire_key_space() {
+    let mut map = ContiguousMap::new();
+    for index in 0..=15 {
+        map.push_back(Tiny::new(index).unwrap(), index).unwrap();
+    }
+    assert_eq!(1, map.num_contiguous_regions());
+    assert_eq!(16, map.len());
+    assert_eq!(Some(16), map.try_len());
+
+    // the map is full so inserting only overwrites
+    map.insert(Tiny::HIGHEST, 0);
+    map.insert_slice(Tiny::LOWEST, &[0; 20]);
+    assert_eq!(16, map.len());
+    assert_eq!(Some(16), map.try_len());
+    assert!(map.push_back(Tiny::HIGHEST, 1).is_err());
+}
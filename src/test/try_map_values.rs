@@ -0,0 +1,51 @@
+use crate::{cmap, test_util::assert_regions, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map: ContiguousMap<usize, &str> = ContiguousMap::new();
+    let map = map.try_map_values(|value| value.parse::<i32>()).unwrap();
+    assert_regions(&map, &[]);
+}
+
+#[test]
+fn all_valid() {
+    let map = cmap!(
+        1 => "11", "12";
+        5 => "15";
+    );
+    let map = map.try_map_values(|value| value.parse::<i32>()).unwrap();
+    assert_eq!(3, map.len());
+    assert_regions(&map, &[(1, vec![11, 12]), (5, vec![15])]);
+}
+
+#[test]
+fn invalid_value() {
+    let map = cmap!(
+        1 => "11", "twelve";
+        5 => "15";
+    );
+    let result = map.try_map_values(|value| value.parse::<i32>());
+    assert_eq!(
+        Err("twelve".parse::<i32>().unwrap_err()),
+        result.map(|_| ())
+    );
+}
+
+#[test]
+fn stops_at_first_error() {
+    let map = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    let mut seen = Vec::new();
+    let result = map.try_map_values(|value| {
+        seen.push(value);
+        if value == 12 {
+            Err(value)
+        } else {
+            Ok(value)
+        }
+    });
+    assert_eq!(Err(12), result.map(|_| ()));
+    assert_eq!(vec![11, 12], seen);
+}
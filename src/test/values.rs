@@ -0,0 +1,34 @@
+use super::assert_de_iter_empty;
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let map = ContiguousMap::<usize, i32>::new();
+    assert_de_iter_empty(map.values());
+}
+
+#[test]
+fn forward() {
+    let map = cmap!(10 => 0, 1; 20 => 2);
+    assert_eq!(vec![&0, &1, &2], map.values().collect::<Vec<_>>());
+}
+
+#[test]
+fn reverse() {
+    let map = cmap!(10 => 0, 1; 20 => 2);
+    assert_eq!(vec![&2, &1, &0], map.values().rev().collect::<Vec<_>>());
+}
+
+#[test]
+fn exact_size() {
+    let map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0;
+    );
+    let mut iter = map.values();
+    assert_eq!(4, iter.len());
+    assert_eq!((4, Some(4)), iter.size_hint());
+    iter.next();
+    iter.next_back();
+    assert_eq!(2, iter.len());
+}
@@ -0,0 +1,41 @@
+use super::{assert_de_iter_empty, assert_map_same};
+use crate::{cmap, ContiguousMap};
+
+#[test]
+fn empty() {
+    let mut map = ContiguousMap::<usize, i32>::new();
+    assert_de_iter_empty(map.values_mut());
+}
+
+#[test]
+fn modify() {
+    let mut map = cmap!(10 => 0, 1; 20 => 2);
+    for value in map.values_mut() {
+        *value += 10;
+    }
+    assert_map_same(&map, [(10, vec![10, 11]), (20, vec![12])]);
+}
+
+#[test]
+fn reverse() {
+    let mut map = cmap!(10 => 0, 1; 20 => 2);
+    let mut values = map.values_mut();
+    assert_eq!(&mut 2, values.next_back().unwrap());
+    assert_eq!(&mut 0, values.next().unwrap());
+    assert_eq!(&mut 1, values.next_back().unwrap());
+    assert_de_iter_empty(values);
+}
+
+#[test]
+fn exact_size() {
+    let mut map = cmap!(
+        10 => 0, 1, 2;
+        20 => 0;
+    );
+    let mut iter = map.values_mut();
+    assert_eq!(4, iter.len());
+    assert_eq!((4, Some(4)), iter.size_hint());
+    iter.next();
+    iter.next_back();
+    assert_eq!(2, iter.len());
+}
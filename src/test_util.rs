@@ -0,0 +1,194 @@
+//! Helpers for asserting the internal layout of a [`ContiguousMap`].
+//!
+//! These are only available with the `test-util` feature enabled.
+//! They are intended for use in the tests of crates that build
+//! [`ContiguousMap`]s and want to assert on how the values are grouped up
+//! into contiguous regions, not just on the observable key value pairs.
+
+use crate::{ContiguousMap, Key};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Asserts that a ContiguousMap is upholding
+/// all required internal invariants.
+///
+/// # Invariants
+/// 1. The map does not contain any empty vectors.
+/// 2. The map contains at most one value for every key.
+///    For example, a map with an entry of 0 => 1, 2, 3
+///    and 2 => 4 would contain two values for the key of 2
+///    and therefore be invalid.
+/// 3. Any entires that may be merged must be merged.
+///    For example, a map containing the entires 0 => 1, 2
+///    and 2 => 3 would be able to represent the same values
+///    with a single merged entry of 0 => 1, 2, 3.
+///    Therefore the map is invalid.
+/// 4. There are no values with a key outside of the range
+///    of valid keys.
+///    For example, a map with a key type of usize and an entry
+///    of usize::MAX => 1, 2 would have a value with a key of
+///    usize::MAX+1.  This is outside the range of the key type
+///    and therefore the map is invalid.
+/// 5. The internal length equal to the total number of values
+///    in the map.
+#[track_caller]
+pub fn assert_valid<K: Key + Debug, V: Debug>(map: &ContiguousMap<K, V>) {
+    // check invariant 1
+    for (_, vector) in map.map.iter() {
+        assert!(
+            !vector.is_empty(),
+            "Internal ContiguousMap invariant violation: Contains an empty vector.\nmap{:?}",
+            map.map,
+        );
+    }
+    // check invariant 4
+    for (key, vector) in map.map.iter() {
+        assert!(
+            key.add_usize(vector.len() - 1).is_some(),
+            "Internal ContiguousMap invariant violation: Entry at key {:?} overflows the key type.\nmap{:?}",
+            key,
+            map.map,
+        );
+    }
+    // check invariant 2
+    for ((key, vec), (next_key, _)) in map.map.iter().zip(map.map.iter().skip(1)) {
+        let key_of_last_in_first = key.add_usize(vec.len() - 1).unwrap();
+        assert!(
+            key_of_last_in_first < *next_key,
+            "Internal ContiguousMap invariant violation: Multiple values for key of {:?}.\nmap:{:?}",
+            next_key,
+            map.map,
+        );
+    }
+    // check invariant 3
+    for ((key, vec), (next_key, _)) in map.map.iter().zip(map.map.iter().skip(1)) {
+        let key_after_last_in_first = key.add_usize(vec.len());
+        assert!(
+            key_after_last_in_first.as_ref() != Some(next_key),
+            "Internal ContiguousMap invariant violation: Map contains mergeable entires with keys {:?} and {:?}.\nmap:{:?}",
+            key,
+            next_key,
+            map.map,
+        );
+    }
+    // check invariant 5
+    let len: usize = map.map.values().map(|vec| vec.len()).sum();
+    assert!(
+        len == map.length,
+        "Internal ContiguousMap invariant violation: Map's stored length of {} does not match the number of values of {}.\nmap:{:?}",
+        map.length,
+        len,
+        map.map,
+    );
+}
+
+/// Asserts that a ContiguousMap contains exactly the given contiguous regions.
+/// The regions must be given in order sorted by their keys.
+/// Also asserts that the map is valid using [`assert_valid()`].
+#[track_caller]
+pub fn assert_regions<K: Key + Debug, V: Debug + PartialEq>(
+    map: &ContiguousMap<K, V>,
+    expected: &[(K, Vec<V>)],
+) {
+    assert_valid(map);
+    assert!(
+        expected.len() == map.map.len(),
+        "Expected {} entries in the internal map, not {}.\nmap:{:?}",
+        expected.len(),
+        map.map.len(),
+        map.map
+    );
+    for (index, ((expected_start, expected_vec), (start, vec))) in
+        expected.iter().zip(map.map.iter()).enumerate()
+    {
+        assert!(
+            expected_start == start,
+            "Expected the entry at index {} of the internal map to start at a key value of {:?}, not {:?}.\nmap: {:?}",
+            index, expected_start, start, map.map
+        );
+        assert!(
+            expected_vec == vec,
+            "Expected the vector starting at the key value of {:?} to be {:?}, not {:?}.\nmap: {:?}",
+            start,
+            expected_vec,
+            vec,
+            map.map
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cmap;
+
+    #[test]
+    fn valid_empty() {
+        assert_valid(&ContiguousMap::<u8, u8>::new());
+    }
+
+    #[test]
+    fn regions_match() {
+        let map = cmap!(
+            -2i8 => 'a', 'b';
+            5 => 'c';
+        );
+        assert_regions(&map, &[(-2, vec!['a', 'b']), (5, vec!['c'])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mergeable entires")]
+    fn invalid_unmerged() {
+        let mut map = cmap!(0u8 => 1, 2);
+        map.map.insert(2, vec![3]);
+        map.length += 1;
+        assert_valid(&map);
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiple values")]
+    fn invalid_overlapping() {
+        let mut map = cmap!(0u8 => 1, 2);
+        map.map.insert(1, vec![3]);
+        map.length += 1;
+        assert_valid(&map);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows the key type")]
+    fn invalid_overflow() {
+        let mut map = ContiguousMap::new();
+        map.map.insert(u8::MAX, vec![1, 2]);
+        map.length = 2;
+        assert_valid(&map);
+    }
+
+    #[test]
+    #[should_panic(expected = "stored length")]
+    fn invalid_length() {
+        let mut map = cmap!(0u8 => 1, 2);
+        map.length = 3;
+        assert_valid(&map);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 1 entries")]
+    fn regions_count_different() {
+        let map = cmap!(0u8 => 1; 5 => 2);
+        assert_regions(&map, &[(0, vec![1])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "to start at a key value of 1")]
+    fn regions_start_different() {
+        let map = cmap!(0u8 => 1);
+        assert_regions(&map, &[(1, vec![1])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "to be [2]")]
+    fn regions_values_different() {
+        let map = cmap!(0u8 => 1);
+        assert_regions(&map, &[(0, vec![2])]);
+    }
+}
@@ -0,0 +1,61 @@
+//! This file ensures that the bounded_key macro works as expected across crate boundaries.
+//!
+//! I intentionally do not import anything from the contiguous_map crate to ensure
+//! that the macro does not rely on these imports.
+
+#![no_implicit_prelude]
+
+::contiguous_map::bounded_key!(Port, u16, 1024..=65535);
+
+::contiguous_map::bounded_key!(
+    /// A temperature in degrees.
+    pub Celsius, i8, -40..=50
+);
+
+#[test]
+fn new_rejects_out_of_range() {
+    ::std::assert_eq!(::std::option::Option::None, Port::new(1023));
+    ::std::assert_eq!(1024, Port::new(1024).unwrap().get());
+    ::std::assert_eq!(65535, Port::new(65535).unwrap().get());
+    ::std::assert_eq!(::std::option::Option::None, Celsius::new(-41));
+    ::std::assert_eq!(::std::option::Option::None, Celsius::new(51));
+}
+
+#[test]
+fn try_from_index_rejects_out_of_range() {
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        <Port as ::contiguous_map::TryFromIndex>::try_from_index(80)
+    );
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        ::contiguous_map::Key::sub_one(&Port::new(1024).unwrap())
+    );
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        ::contiguous_map::Key::add_one(&Celsius::new(50).unwrap())
+    );
+    ::std::assert_eq!(
+        Celsius::new(-39),
+        ::contiguous_map::Key::add_one(&Celsius::new(-40).unwrap())
+    );
+}
+
+#[test]
+fn bounds() {
+    ::std::assert_eq!(1024, <Port as ::contiguous_map::BoundedKey>::LOWEST.get());
+    ::std::assert_eq!(-40, <Celsius as ::contiguous_map::BoundedKey>::LOWEST.get());
+    ::std::assert_eq!(50, <Celsius as ::contiguous_map::BoundedKey>::HIGHEST.get());
+}
+
+#[test]
+fn adjacent_keys_merge() {
+    let mut map = ::contiguous_map::ContiguousMap::new();
+    map.insert(Port::new(1025).unwrap(), 1);
+    map.insert(Port::new(1024).unwrap(), 0);
+    ::std::assert_eq!(1, map.num_contiguous_regions());
+    ::std::assert_eq!(
+        ::std::option::Option::Some(&[0, 1][..]),
+        map.get_slice(Port::new(1024).unwrap()..)
+    );
+}
@@ -96,11 +96,20 @@ mod non_copy {
             Some(Self(self.0.checked_add(1)?))
         }
 
+        fn sub_one(&self) -> Option<Self> {
+            Some(Self(self.0.checked_sub(1)?))
+        }
+
         fn add_usize(&self, num: usize) -> Option<Self> {
             use ::std::convert::TryInto;
             Some(Self(self.0.checked_add(num.try_into().ok()?)?))
         }
 
+        fn sub_usize(&self, num: usize) -> Option<Self> {
+            use ::std::convert::TryInto;
+            Some(Self(self.0.checked_sub(num.try_into().ok()?)?))
+        }
+
         fn difference(&self, smaller: &Self) -> Option<usize> {
             Some(self.0.checked_sub(smaller.0)? as usize)
         }
@@ -0,0 +1,70 @@
+//! This file ensures that the impl_step_key macro works as expected across crate boundaries.
+//!
+//! I intentionally do not import anything from the contiguous_map crate to ensure
+//! that the macro does not rely on these imports.
+
+#![no_implicit_prelude]
+
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+struct Bucket(u64);
+
+::contiguous_map::impl_step_key!(Bucket(u64), 100);
+
+#[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+struct Offset(u8);
+
+::contiguous_map::impl_step_key!(Offset(u8), 10, 5);
+
+#[test]
+fn index_is_number_of_steps() {
+    ::std::assert_eq!(0, ::contiguous_map::ToIndex::to_index(&Bucket(0)));
+    ::std::assert_eq!(3, ::contiguous_map::ToIndex::to_index(&Bucket(300)));
+    ::std::assert_eq!(
+        ::std::option::Option::Some(Bucket(700)),
+        <Bucket as ::contiguous_map::TryFromIndex>::try_from_index(7)
+    );
+}
+
+#[test]
+fn adjacent_buckets_merge() {
+    let mut map = ::contiguous_map::ContiguousMap::new();
+    map.insert(Bucket(100), 1);
+    map.insert(Bucket(300), 3);
+    ::std::assert_eq!(2, map.num_contiguous_regions());
+    map.insert(Bucket(200), 2);
+    ::std::assert_eq!(1, map.num_contiguous_regions());
+    ::std::assert_eq!(
+        ::std::option::Option::Some(&[1, 2, 3][..]),
+        map.get_slice(Bucket(100)..=Bucket(300))
+    );
+}
+
+#[test]
+fn overflow() {
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        <Bucket as ::contiguous_map::TryFromIndex>::try_from_index(u64::MAX)
+    );
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        ::contiguous_map::Key::add_one(&Offset(255))
+    );
+}
+
+#[test]
+fn origin() {
+    ::std::assert_eq!(0, ::contiguous_map::ToIndex::to_index(&Offset(5)));
+    ::std::assert_eq!(2, ::contiguous_map::ToIndex::to_index(&Offset(25)));
+    ::std::assert_eq!(
+        ::std::option::Option::Some(Offset(15)),
+        ::contiguous_map::Key::add_one(&Offset(5))
+    );
+    ::std::assert_eq!(
+        ::std::option::Option::Some(Offset(255)),
+        ::contiguous_map::Key::add_usize(&Offset(5), 25)
+    );
+    ::std::assert_eq!(
+        ::std::option::Option::None,
+        ::contiguous_map::Key::add_usize(&Offset(5), 26)
+    );
+}
@@ -0,0 +1,23 @@
+//! Smoke test that the crate can be used from a `no_std` crate with only `alloc`.
+//!
+//! Run with `cargo test --no-default-features` to also build the library without `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use contiguous_map::{cmap, ContiguousMap};
+
+#[test]
+fn construct_and_iterate() {
+    let mut map: ContiguousMap<u32, u8> = cmap!(
+        1 => 11, 12;
+        5 => 15;
+    );
+    map.insert(3, 13);
+    let entries: Vec<(u32, u8)> = map.iter().map(|(key, value)| (key, *value)).collect();
+    assert_eq!(entries, [(1, 11), (2, 12), (3, 13), (5, 15)]);
+    let regions: Vec<(u32, &[u8])> = map.iter_slice().map(|(key, slice)| (*key, slice)).collect();
+    assert_eq!(regions, [(1, &[11, 12, 13][..]), (5, &[15][..])]);
+}